@@ -1,22 +1,32 @@
-use crate::{SELECTED_PARAMETER_SET, auxiliary::{bytes_to_bits, get_zeta_from_index}, bytevec::ByteVec, polynomial::Poly16};
+use crate::{N, Q, auxiliary::{get_gamma_montgomery, get_zeta_montgomery}, bytevec::ByteVec, polynomial::{Poly16, PolyI16}, reduce::{barrett_reduce, canonical, fqmul, to_montgomery}};
 use sha3::{Shake128, digest::{ExtendableOutput, Update, XofReader}};
 
 pub fn ntt(f: Poly16) -> Poly16 {
-    let mut transformed = f.clone();
+    ntt_i16(f.into()).to_canonical()
+}
+
+pub fn ntt_inv(ft: Poly16) -> Poly16 {
+    ntt_inv_i16(ft.into()).to_canonical()
+}
+
+// Input coefficients in (-q, q); output reduced to centered representatives
+pub fn ntt_i16(f: PolyI16) -> PolyI16 {
+    let mut transformed = f;
 
+    // Each layer grows coefficients by less than q, so they stay below 8q in an i16
     let mut i = 1;
     let mut len = 128;
     while len >= 2 {
         let mut start = 0;
         while start < 256 {
-            let zeta = get_zeta_from_index(i);
+            let zeta = get_zeta_montgomery(i);
             i += 1;
 
             let mut j = start;
             while j  < start + len {
-                let t = ((zeta as u32 * transformed[j + len] as u32) % SELECTED_PARAMETER_SET.q as u32) as u16;
-                transformed[j + len] = (transformed[j] as i32 - t as i32).rem_euclid(SELECTED_PARAMETER_SET.q as i32) as u16;
-                transformed[j] = ((transformed[j] as u32 + t as u32) % SELECTED_PARAMETER_SET.q as u32) as u16;
+                let t = fqmul(zeta, transformed[j + len]);
+                transformed[j + len] = transformed[j] - t;
+                transformed[j] += t;
 
                 j += 1;
             }
@@ -27,11 +37,16 @@ pub fn ntt(f: Poly16) -> Poly16 {
         len /= 2;
     }
     
-    transformed
+    transformed.reduce()
 }
 
-pub fn ntt_inv(ft: Poly16) -> Poly16 {
-    let mut inverse = ft.clone();
+// Input coefficients in (-2^14, 2^14), so sums of lazily reduced products fit;
+// output coefficients in (-q, q)
+pub fn ntt_inv_i16(ft: PolyI16) -> PolyI16 {
+    // 128^-1 mod q, in Montgomery form
+    const F: i16 = to_montgomery(3303);
+
+    let mut inverse = ft;
 
     let mut i = 127;
     
@@ -39,14 +54,14 @@ pub fn ntt_inv(ft: Poly16) -> Poly16 {
     while len <= 128 {
         let mut start = 0;
         while start < 256 {
-            let zeta = get_zeta_from_index(i);
+            let zeta = get_zeta_montgomery(i);
             i -= 1;
 
             let mut j = start;
             while j < start + len {
                 let t = inverse[j];
-                inverse[j] = (t + inverse[j + len]) % SELECTED_PARAMETER_SET.q as u16;
-                inverse[j + len] = (zeta*(inverse[j+len] - t)).rem_euclid(SELECTED_PARAMETER_SET.q as u16);
+                inverse[j] = barrett_reduce(t + inverse[j + len]);
+                inverse[j + len] = fqmul(zeta, inverse[j + len] - t);
 
                 j += 1;
             }
@@ -57,12 +72,15 @@ pub fn ntt_inv(ft: Poly16) -> Poly16 {
         len *= 2;
     }
 
-    inverse = (inverse*3303) % SELECTED_PARAMETER_SET.q as u16;
+    for j in 0..256 {
+        inverse[j] = fqmul(inverse[j], F);
+    }
+
     inverse
 }
 
 pub fn sample_ntt(rho: [u8;32], b1: u8, b2: u8) -> Poly16 {
-    let mut out = [0u16; SELECTED_PARAMETER_SET.n];
+    let mut out = [0u16; N];
 
     let mut ctx = Shake128::default();
     ctx.update(&rho);
@@ -70,16 +88,17 @@ pub fn sample_ntt(rho: [u8;32], b1: u8, b2: u8) -> Poly16 {
     let mut reader = ctx.finalize_xof();
     let mut j = 0;
 
-    while j < 255 {
-        let c = reader.read_boxed(3);
+    while j < 256 {
+        let mut c = [0u8; 3];
+        reader.read(&mut c);
         let d1 = c[0] as u16 + 256*(c[1]%16) as u16;
         let d2: u16 = (c[1]/16) as u16 + 16*c[2] as u16;
-        if d1 < SELECTED_PARAMETER_SET.q as u16 {
-            out[j] = d1 as u16;
+        if d1 < Q as u16 {
+            out[j] = d1;
             j += 1;
         }
-        if d2 < SELECTED_PARAMETER_SET.q as u16 {
-            out[j] = d2 as u16;
+        if d2 < Q as u16 && j < 256 {
+            out[j] = d2;
             j += 1;
         }
     }
@@ -87,15 +106,16 @@ pub fn sample_ntt(rho: [u8;32], b1: u8, b2: u8) -> Poly16 {
 }
 
 pub fn sample_poly_cbd<const ETA: usize>(bytes: ByteVec) -> Poly16 {
-    assert!(ETA == 2 || ETA == 3, "n should be 2 or 3");
+    const { assert!(ETA == 2 || ETA == 3, "eta should be 2 or 3") };
 
     let bytes_slice = match &bytes {
         ByteVec::Vec128(arr) => &arr[..],
         ByteVec::Vec192(arr) => &arr[..],
     };
 
-    let b: Vec<u8> = bytes_to_bits(bytes_slice);
-    let mut f = [0u16;SELECTED_PARAMETER_SET.n];
+    // Bit k of BytesToBits(B), read in place so that no buffer is allocated
+    let b = |k: usize| (bytes_slice[k / 8] >> (k % 8)) & 1;
+    let mut f = [0u16;N];
 
     let eta = ETA;
 
@@ -105,14 +125,45 @@ pub fn sample_poly_cbd<const ETA: usize>(bytes: ByteVec) -> Poly16 {
         let mut y = 0i32;
         
         for j in 0..eta {
-            x += b[2*i*eta + j] as i32;
-            y += b[2*i*eta + eta + j] as i32;
+            x += b(2*i*eta + j) as i32;
+            y += b(2*i*eta + eta + j) as i32;
         }
 
-        f[i] = (((x - y) as i16).rem_euclid(SELECTED_PARAMETER_SET.q as i16)) as u16;
+        f[i] = (((x - y) as i16).rem_euclid(Q as i16)) as u16;
 
         i += 1;
     }
 
     Poly16::new(&f)
-}
\ No newline at end of file
+}
+
+pub fn multiply_ntts(f: Poly16, g: Poly16) -> Poly16 {
+    multiply_ntts_i16(f.into(), g.into()).to_canonical()
+}
+
+// Input coefficients in (-q, q); output coefficients in (-q, q)
+pub fn multiply_ntts_i16(f: PolyI16, g: PolyI16) -> PolyI16 {
+    let mut h = [0i16; N];
+
+    for i in 0..128 {
+        let (c0, c1) = base_case_multiply_montgomery(f[2*i], f[2*i + 1], g[2*i], g[2*i + 1], get_gamma_montgomery(i));
+        h[2*i] = c0;
+        h[2*i + 1] = c1;
+    }
+
+    PolyI16::new(&h)
+}
+
+pub fn base_case_multiply(a0: u16, a1: u16, b0: u16, b1: u16, gamma: u16) -> (u16, u16) {
+    let (c0, c1) = base_case_multiply_montgomery(a0 as i16, a1 as i16, b0 as i16, b1 as i16, to_montgomery(gamma as i16));
+
+    (canonical(c0), canonical(c1))
+}
+
+fn base_case_multiply_montgomery(a0: i16, a1: i16, b0: i16, b1: i16, gamma: i16) -> (i16, i16) {
+    // Both sums carry a factor R^-1, which to_montgomery cancels
+    let c0 = fqmul(fqmul(a1, b1), gamma) + fqmul(a0, b0);
+    let c1 = fqmul(a0, b1) + fqmul(a1, b0);
+
+    (to_montgomery(c0), to_montgomery(c1))
+}
@@ -0,0 +1,281 @@
+use core::fmt;
+
+use rand_core::CryptoRngCore;
+
+use crate::{ML_KEM_512, ML_KEM_768, ML_KEM_1024, MlKem512, MlKem768, MlKem1024, ParameterSetConstants, error::Error, ml_kem::{self, Ciphertext, DecapsulationKey, EncapsulationKey, SharedSecret}};
+#[cfg(feature = "getrandom")]
+use crate::rng::OsRng;
+
+// Parameter set chosen at runtime, e.g. after parsing a peer's message
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnyMlKem {
+    MlKem512,
+    MlKem768,
+    MlKem1024,
+}
+
+// Variants are stored inline so the wrappers stay Copy and allocation-free
+#[allow(clippy::large_enum_variant)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnyEncapsulationKey {
+    MlKem512(EncapsulationKey<MlKem512>),
+    MlKem768(EncapsulationKey<MlKem768>),
+    MlKem1024(EncapsulationKey<MlKem1024>),
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum AnyDecapsulationKey {
+    MlKem512(DecapsulationKey<MlKem512>),
+    MlKem768(DecapsulationKey<MlKem768>),
+    MlKem1024(DecapsulationKey<MlKem1024>),
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnyCiphertext {
+    MlKem512(Ciphertext<MlKem512>),
+    MlKem768(Ciphertext<MlKem768>),
+    MlKem1024(Ciphertext<MlKem1024>),
+}
+
+impl AnyMlKem {
+    pub const ALL: [AnyMlKem; 3] = [AnyMlKem::MlKem512, AnyMlKem::MlKem768, AnyMlKem::MlKem1024];
+
+    pub fn parameters(self) -> &'static ParameterSetConstants {
+        match self {
+            AnyMlKem::MlKem512 => &ML_KEM_512,
+            AnyMlKem::MlKem768 => &ML_KEM_768,
+            AnyMlKem::MlKem1024 => &ML_KEM_1024,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AnyMlKem::MlKem512 => "ML-KEM-512",
+            AnyMlKem::MlKem768 => "ML-KEM-768",
+            AnyMlKem::MlKem1024 => "ML-KEM-1024",
+        }
+    }
+
+    // id-alg-ml-kem-* under NIST's 2.16.840.1.101.3.4.4 arc
+    pub fn oid(self) -> &'static str {
+        match self {
+            AnyMlKem::MlKem512 => "2.16.840.1.101.3.4.4.1",
+            AnyMlKem::MlKem768 => "2.16.840.1.101.3.4.4.2",
+            AnyMlKem::MlKem1024 => "2.16.840.1.101.3.4.4.3",
+        }
+    }
+
+    // IANA TLS Supported Groups codepoints
+    pub fn tls_group(self) -> u16 {
+        match self {
+            AnyMlKem::MlKem512 => 0x0200,
+            AnyMlKem::MlKem768 => 0x0201,
+            AnyMlKem::MlKem1024 => 0x0202,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+
+    pub fn from_oid(oid: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.oid() == oid)
+    }
+
+    pub fn from_tls_group(group: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.tls_group() == group)
+    }
+
+    #[cfg(feature = "getrandom")]
+    pub fn keygen(self) -> Result<(AnyEncapsulationKey, AnyDecapsulationKey), Error> {
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    pub fn keygen_with_rng(self, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(AnyEncapsulationKey, AnyDecapsulationKey), Error> {
+        match self {
+            AnyMlKem::MlKem512 => {
+                let (ek, dk) = ml_kem::keygen_with_rng::<MlKem512>(rng)?;
+                Ok((AnyEncapsulationKey::MlKem512(ek), AnyDecapsulationKey::MlKem512(dk)))
+            }
+            AnyMlKem::MlKem768 => {
+                let (ek, dk) = ml_kem::keygen_with_rng::<MlKem768>(rng)?;
+                Ok((AnyEncapsulationKey::MlKem768(ek), AnyDecapsulationKey::MlKem768(dk)))
+            }
+            AnyMlKem::MlKem1024 => {
+                let (ek, dk) = ml_kem::keygen_with_rng::<MlKem1024>(rng)?;
+                Ok((AnyEncapsulationKey::MlKem1024(ek), AnyDecapsulationKey::MlKem1024(dk)))
+            }
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    pub fn keygen_with_options(self, options: ml_kem::KeyGenOptions) -> Result<(AnyEncapsulationKey, AnyDecapsulationKey), Error> {
+        self.keygen_with_options_and_rng(options, &mut OsRng)
+    }
+
+    pub fn keygen_with_options_and_rng(self, options: ml_kem::KeyGenOptions, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(AnyEncapsulationKey, AnyDecapsulationKey), Error> {
+        match self {
+            AnyMlKem::MlKem512 => {
+                let (ek, dk) = ml_kem::keygen_with_options_and_rng::<MlKem512>(options, rng)?;
+                Ok((AnyEncapsulationKey::MlKem512(ek), AnyDecapsulationKey::MlKem512(dk)))
+            }
+            AnyMlKem::MlKem768 => {
+                let (ek, dk) = ml_kem::keygen_with_options_and_rng::<MlKem768>(options, rng)?;
+                Ok((AnyEncapsulationKey::MlKem768(ek), AnyDecapsulationKey::MlKem768(dk)))
+            }
+            AnyMlKem::MlKem1024 => {
+                let (ek, dk) = ml_kem::keygen_with_options_and_rng::<MlKem1024>(options, rng)?;
+                Ok((AnyEncapsulationKey::MlKem1024(ek), AnyDecapsulationKey::MlKem1024(dk)))
+            }
+        }
+    }
+
+    // Validates a peer's ek with the FIPS 203 §7.2 checks before it can be used
+    pub fn encapsulation_key_from_bytes(self, bytes: &[u8]) -> Result<AnyEncapsulationKey, Error> {
+        match self {
+            AnyMlKem::MlKem512 => Ok(AnyEncapsulationKey::MlKem512(EncapsulationKey::try_from(bytes)?)),
+            AnyMlKem::MlKem768 => Ok(AnyEncapsulationKey::MlKem768(EncapsulationKey::try_from(bytes)?)),
+            AnyMlKem::MlKem1024 => Ok(AnyEncapsulationKey::MlKem1024(EncapsulationKey::try_from(bytes)?)),
+        }
+    }
+
+    // Checks H(ek) inside dk so corrupted or mixed-up key files are rejected
+    pub fn decapsulation_key_from_bytes(self, bytes: &[u8]) -> Result<AnyDecapsulationKey, Error> {
+        match self {
+            AnyMlKem::MlKem512 => Ok(AnyDecapsulationKey::MlKem512(DecapsulationKey::try_from(bytes)?)),
+            AnyMlKem::MlKem768 => Ok(AnyDecapsulationKey::MlKem768(DecapsulationKey::try_from(bytes)?)),
+            AnyMlKem::MlKem1024 => Ok(AnyDecapsulationKey::MlKem1024(DecapsulationKey::try_from(bytes)?)),
+        }
+    }
+
+    pub fn decapsulation_key_from_seed(self, seed: [u8; 64]) -> AnyDecapsulationKey {
+        match self {
+            AnyMlKem::MlKem512 => AnyDecapsulationKey::MlKem512(DecapsulationKey::from_seed(seed)),
+            AnyMlKem::MlKem768 => AnyDecapsulationKey::MlKem768(DecapsulationKey::from_seed(seed)),
+            AnyMlKem::MlKem1024 => AnyDecapsulationKey::MlKem1024(DecapsulationKey::from_seed(seed)),
+        }
+    }
+
+    pub fn ciphertext_from_bytes(self, bytes: &[u8]) -> Result<AnyCiphertext, Error> {
+        match self {
+            AnyMlKem::MlKem512 => Ok(AnyCiphertext::MlKem512(Ciphertext::try_from(bytes)?)),
+            AnyMlKem::MlKem768 => Ok(AnyCiphertext::MlKem768(Ciphertext::try_from(bytes)?)),
+            AnyMlKem::MlKem1024 => Ok(AnyCiphertext::MlKem1024(Ciphertext::try_from(bytes)?)),
+        }
+    }
+}
+
+impl AnyEncapsulationKey {
+    pub fn parameter_set(&self) -> AnyMlKem {
+        match self {
+            AnyEncapsulationKey::MlKem512(_) => AnyMlKem::MlKem512,
+            AnyEncapsulationKey::MlKem768(_) => AnyMlKem::MlKem768,
+            AnyEncapsulationKey::MlKem1024(_) => AnyMlKem::MlKem1024,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            AnyEncapsulationKey::MlKem512(ek) => ek.as_bytes(),
+            AnyEncapsulationKey::MlKem768(ek) => ek.as_bytes(),
+            AnyEncapsulationKey::MlKem1024(ek) => ek.as_bytes(),
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    pub fn encaps(&self) -> Result<(SharedSecret, AnyCiphertext), Error> {
+        self.encaps_with_rng(&mut OsRng)
+    }
+
+    pub fn encaps_with_rng(&self, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(SharedSecret, AnyCiphertext), Error> {
+        match self {
+            AnyEncapsulationKey::MlKem512(ek) => {
+                let (k, c) = ml_kem::encaps_with_rng(ek, rng)?;
+                Ok((k, AnyCiphertext::MlKem512(c)))
+            }
+            AnyEncapsulationKey::MlKem768(ek) => {
+                let (k, c) = ml_kem::encaps_with_rng(ek, rng)?;
+                Ok((k, AnyCiphertext::MlKem768(c)))
+            }
+            AnyEncapsulationKey::MlKem1024(ek) => {
+                let (k, c) = ml_kem::encaps_with_rng(ek, rng)?;
+                Ok((k, AnyCiphertext::MlKem1024(c)))
+            }
+        }
+    }
+}
+
+impl AnyDecapsulationKey {
+    pub fn parameter_set(&self) -> AnyMlKem {
+        match self {
+            AnyDecapsulationKey::MlKem512(_) => AnyMlKem::MlKem512,
+            AnyDecapsulationKey::MlKem768(_) => AnyMlKem::MlKem768,
+            AnyDecapsulationKey::MlKem1024(_) => AnyMlKem::MlKem1024,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            AnyDecapsulationKey::MlKem512(dk) => dk.as_bytes(),
+            AnyDecapsulationKey::MlKem768(dk) => dk.as_bytes(),
+            AnyDecapsulationKey::MlKem1024(dk) => dk.as_bytes(),
+        }
+    }
+
+    pub fn to_seed(&self) -> Option<[u8; 64]> {
+        match self {
+            AnyDecapsulationKey::MlKem512(dk) => dk.to_seed(),
+            AnyDecapsulationKey::MlKem768(dk) => dk.to_seed(),
+            AnyDecapsulationKey::MlKem1024(dk) => dk.to_seed(),
+        }
+    }
+
+    pub fn encapsulation_key(&self) -> AnyEncapsulationKey {
+        match self {
+            AnyDecapsulationKey::MlKem512(dk) => AnyEncapsulationKey::MlKem512(dk.encapsulation_key()),
+            AnyDecapsulationKey::MlKem768(dk) => AnyEncapsulationKey::MlKem768(dk.encapsulation_key()),
+            AnyDecapsulationKey::MlKem1024(dk) => AnyEncapsulationKey::MlKem1024(dk.encapsulation_key()),
+        }
+    }
+
+    // Fails only if the ciphertext belongs to a different parameter set
+    pub fn decaps(&self, c: &AnyCiphertext) -> Result<SharedSecret, Error> {
+        match (self, c) {
+            (AnyDecapsulationKey::MlKem512(dk), AnyCiphertext::MlKem512(c)) => Ok(ml_kem::decaps(dk, c)),
+            (AnyDecapsulationKey::MlKem768(dk), AnyCiphertext::MlKem768(c)) => Ok(ml_kem::decaps(dk, c)),
+            (AnyDecapsulationKey::MlKem1024(dk), AnyCiphertext::MlKem1024(c)) => Ok(ml_kem::decaps(dk, c)),
+            _ => Err(Error::ParameterSetMismatch),
+        }
+    }
+}
+
+// Only the variant is shown, never the key
+impl fmt::Debug for AnyDecapsulationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyDecapsulationKey::MlKem512(_) => f.write_str("AnyDecapsulationKey::MlKem512 { .. }"),
+            AnyDecapsulationKey::MlKem768(_) => f.write_str("AnyDecapsulationKey::MlKem768 { .. }"),
+            AnyDecapsulationKey::MlKem1024(_) => f.write_str("AnyDecapsulationKey::MlKem1024 { .. }"),
+        }
+    }
+}
+
+impl AnyCiphertext {
+    pub fn parameter_set(&self) -> AnyMlKem {
+        match self {
+            AnyCiphertext::MlKem512(_) => AnyMlKem::MlKem512,
+            AnyCiphertext::MlKem768(_) => AnyMlKem::MlKem768,
+            AnyCiphertext::MlKem1024(_) => AnyMlKem::MlKem1024,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            AnyCiphertext::MlKem512(c) => c.as_bytes(),
+            AnyCiphertext::MlKem768(c) => c.as_bytes(),
+            AnyCiphertext::MlKem1024(c) => c.as_bytes(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::SELECTED_PARAMETER_SET;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::{N, Q, polynomial::Poly16, reduce::{barrett_reduce, canonical, montgomery_reduce, to_montgomery}};
 
 pub const fn get_bit_reversal_lookup() -> [u8;128] {
     let mut table = [0u8;128];
@@ -19,7 +22,7 @@ pub const fn get_bit_reversal_lookup() -> [u8;128] {
         i += 1;
     }
 
-    return table;
+    table
 }
 
 const fn modpow(base: u8, exponent: u8, modulus: u32) -> u16 {
@@ -31,38 +34,68 @@ const fn modpow(base: u8, exponent: u8, modulus: u32) -> u16 {
         if e % 2 == 1 {
             result = (result * b) % modulus;
         }
-        e = e >> 1;
+        e >>= 1;
         b = (b * b) % modulus;
     }
 
     result as u16
 }
 
+const ZETA_LUT: [i16; 128] = get_zeta_lut();
+const GAMMA_LUT: [i16; 128] = get_gamma_lut();
+
+// ζ^BitRev7(i) mod q
 pub fn get_zeta_from_index(i: usize) -> u16 {
-    const ZETA_LUT: [u16; 128] = get_zeta_lut();
+    canonical(montgomery_reduce(ZETA_LUT[i] as i32))
+}
 
+// ζ^(2·BitRev7(i) + 1) mod q
+pub fn get_gamma_from_index(i: usize) -> u16 {
+    canonical(montgomery_reduce(GAMMA_LUT[i] as i32))
+}
+
+// Same values in Montgomery form, as used by the NTT and MultiplyNTTs
+pub fn get_zeta_montgomery(i: usize) -> i16 {
     ZETA_LUT[i]
 }
 
-const fn get_zeta_lut() -> [u16; 128] {
-    let mut zeta_list = [0u16;128];
+pub fn get_gamma_montgomery(i: usize) -> i16 {
+    GAMMA_LUT[i]
+}
+
+const fn get_zeta_lut() -> [i16; 128] {
+    let mut zeta_list = [0i16;128];
     
     let mut i = 0;
 
     while i < 128 {
-        zeta_list[i] = modpow(17u8, bit_rev(i as u8), SELECTED_PARAMETER_SET.q as u32);
+        zeta_list[i] = barrett_reduce(to_montgomery(modpow(17u8, bit_rev(i as u8), Q) as i16));
         i += 1;
     }
 
     zeta_list
 }
 
+const fn get_gamma_lut() -> [i16; 128] {
+    let mut gamma_list = [0i16;128];
+
+    let mut i = 0;
+
+    while i < 128 {
+        gamma_list[i] = barrett_reduce(to_montgomery(modpow(17u8, 2*bit_rev(i as u8) + 1, Q) as i16));
+        i += 1;
+    }
+
+    gamma_list
+}
+
 pub const fn bit_rev(n: u8) -> u8 {
     const LUT: [u8;128] = get_bit_reversal_lookup();
     
     LUT[n as usize]
 }
 
+#[cfg(feature = "alloc")]
 pub fn bytes_to_bits(bytes: &[u8]) -> Vec<u8> {
     let mut c = bytes.to_vec();
     let l = bytes.len();
@@ -71,20 +104,103 @@ pub fn bytes_to_bits(bytes: &[u8]) -> Vec<u8> {
     for i in 0..l {
         for j in 0..8 {
             b[8*i+j] = c[i] % 2;
-            c[i] = c[i] >> 1;
+            c[i] >>= 1;
         }
     }
 
     b
 }
 
+#[cfg(feature = "alloc")]
 pub fn bits_to_bytes(bits: &[u8]) -> Vec<u8> {
     let l = bits.len();
     let mut bytes = vec![0u8;l/8];
     
-    for i in 0..8*l {
-        bytes[i/8] = bytes[i/8] + bits[i] << (i % 8);
+    for i in 0..l {
+        bytes[i/8] += bits[i] << (i % 8);
     }
 
     bytes
-}
\ No newline at end of file
+}
+
+pub fn byte_encode<const D: usize, const L: usize>(f: Poly16, out: &mut [u8; L]) {
+    const { assert!(D >= 1 && D <= 12 && L == 32*D) };
+
+    let mask = (1u32 << D) - 1;
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    let mut k = 0;
+
+    for i in 0..256 {
+        acc |= (f[i] as u32 & mask) << acc_bits;
+        acc_bits += D;
+
+        while acc_bits >= 8 {
+            out[k] = acc as u8;
+            acc >>= 8;
+            acc_bits -= 8;
+            k += 1;
+        }
+    }
+}
+
+pub fn byte_decode<const D: usize, const L: usize>(bytes: &[u8; L]) -> Poly16 {
+    const { assert!(D >= 1 && D <= 12 && L == 32*D) };
+
+    let q = Q as i32;
+    let mask = (1u32 << D) - 1;
+    let mut f = [0u16; N];
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    let mut k = 0;
+
+    for coeff in f.iter_mut() {
+        while acc_bits < D {
+            acc |= (bytes[k] as u32) << acc_bits;
+            acc_bits += 8;
+            k += 1;
+        }
+
+        let a = acc & mask;
+        acc >>= D;
+        acc_bits -= D;
+
+        if D == 12 {
+            // Reduce mod q with a branch-free conditional subtraction, a < 2q
+            let t = a as i32 - q;
+            *coeff = (t + ((t >> 31) & q)) as u16;
+        } else {
+            *coeff = a as u16;
+        }
+    }
+
+    Poly16::new(&f)
+}
+
+pub fn compress<const D: usize>(f: Poly16) -> Poly16 {
+    // ⌈2^35/q⌉, so that (n·M) >> 35 == n/q for every n < 2^23
+    const M: u64 = (1u64 << 35).div_ceil(Q as u64);
+
+    let q = Q;
+    let mut out = [0u16; N];
+
+    for i in 0..256 {
+        // ⌈(2^d/q)·x⌋ mod 2^d, without a division on (possibly secret) x
+        let n = ((f[i] as u32) << D) + q/2;
+        out[i] = (((n as u64 * M) >> 35) as u32 & ((1 << D) - 1)) as u16;
+    }
+
+    Poly16::new(&out)
+}
+
+pub fn decompress<const D: usize>(f: Poly16) -> Poly16 {
+    let q = Q;
+    let mut out = [0u16; N];
+
+    for i in 0..256 {
+        // ⌈(q/2^d)·y⌋
+        out[i] = ((f[i] as u32 * q + (1 << (D - 1))) >> D) as u16;
+    }
+
+    Poly16::new(&out)
+}
@@ -1,11 +1,11 @@
-use std::{array, io::Read};
+use core::array;
 
-use sha3::{Digest, Sha3_256, Sha3_512, Shake256, digest::{ExtendableOutput, Update}};
+use sha3::{Digest, Sha3_256, Sha3_512, Shake256, digest::{ExtendableOutput, Update, XofReader}};
 
 use crate::bytevec::ByteVec;
 
 pub fn prf<const ETA: usize>(s: [u8; 32], b: u8) -> ByteVec { 
-    assert!(ETA == 2 || ETA == 3, "n should be 2 or 3");
+    const { assert!(ETA == 2 || ETA == 3, "eta should be 2 or 3") };
 
     let mut hasher = Shake256::default();
     hasher.update(&s);
@@ -15,39 +15,53 @@ pub fn prf<const ETA: usize>(s: [u8; 32], b: u8) -> ByteVec {
 
     if ETA == 2 {
         let mut output = [0u8; 128];
-        reader.read(&mut output).expect("Failed");
-        return ByteVec::Vec128(output);
+        reader.read(&mut output);
+        ByteVec::Vec128(output)
     } else {
         let mut output = [0u8; 192];
-        reader.read(&mut output).expect("Failed");
-        return ByteVec::Vec192(output);
+        reader.read(&mut output);
+        ByteVec::Vec192(output)
     }
 }
 
 pub fn h(s: &[u8]) -> [u8;32] {
     let mut hasher = Sha3_256::default();
 
-    Update::update(&mut hasher, &s);
+    Update::update(&mut hasher, s);
     
-    return hasher.finalize().into();
+    hasher.finalize().into()
 }
 
 pub fn j(s: &[u8]) -> [u8;32] {
     let mut hasher = Shake256::default();
     let mut output = [0u8; 32];
 
-    hasher.update(&s);
+    hasher.update(s);
+    hasher.finalize_xof_into(&mut output);
+
+    output
+}
+
+// J(a || b), hashing both parts without concatenating them into a buffer
+pub fn j_concat(a: &[u8], b: &[u8]) -> [u8;32] {
+    let mut hasher = Shake256::default();
+    let mut output = [0u8; 32];
+
+    hasher.update(a);
+    hasher.update(b);
     hasher.finalize_xof_into(&mut output);
 
-    return output;
+    output
 }
 
 pub fn g(c: &[u8]) -> ([u8;32], [u8;32]) {
     let mut hasher = Sha3_512::default();
-    Update::update(&mut hasher, &c);
+    Update::update(&mut hasher, c);
 
     let hash: [u8; 64] = hasher.finalize().into();
-    let (left, right) = hash.split_at(32);
-    let tuple = (left.try_into().unwrap(), right.try_into().unwrap());
-    return tuple
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&hash[..32]);
+    right.copy_from_slice(&hash[32..]);
+    (left, right)
 }
@@ -0,0 +1,33 @@
+use core::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    // Input does not have the size required by the parameter set
+    InvalidLength { expected: usize, actual: usize },
+    // ek fails the FIPS 203 §7.2 modulus check
+    InvalidEncapsulationKey,
+    // H(ek) stored in dk does not match the embedded ek (FIPS 203 §7.3),
+    // or the embedded ek fails the modulus check
+    InvalidDecapsulationKey,
+    // Encaps/Decaps with a freshly generated keypair disagreed
+    PairwiseConsistencyTestFailed,
+    // The random number generator could not provide the seed bytes
+    RngFailure,
+    // Key and ciphertext belong to different parameter sets
+    ParameterSetMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => write!(f, "invalid length: expected {expected} bytes, got {actual}"),
+            Error::InvalidEncapsulationKey => write!(f, "encapsulation key is not reduced mod q"),
+            Error::InvalidDecapsulationKey => write!(f, "decapsulation key hash or embedded encapsulation key is invalid"),
+            Error::PairwiseConsistencyTestFailed => write!(f, "pairwise consistency test failed"),
+            Error::RngFailure => write!(f, "random number generator failed"),
+            Error::ParameterSetMismatch => write!(f, "key and ciphertext use different parameter sets"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,14 @@
+// Derandomized entry points from FIPS 203. The caller is responsible for
+// providing fresh, uniformly random d, z and m; reusing them breaks security.
+
+use crate::{ml_kem::{self, Ciphertext, DecapsulationKey, EncapsulationKey, SharedSecret}, params::ParameterSet};
+
+// Algorithm 16, ML-KEM.KeyGen_internal(d, z)
+pub fn keygen_internal<P: ParameterSet>(d: [u8; 32], z: [u8; 32]) -> (EncapsulationKey<P>, DecapsulationKey<P>) {
+    ml_kem::keygen_internal(d, z)
+}
+
+// Algorithm 17, ML-KEM.Encaps_internal(ek, m)
+pub fn encaps_internal<P: ParameterSet>(ek: &EncapsulationKey<P>, m: [u8; 32]) -> (SharedSecret, Ciphertext<P>) {
+    ml_kem::encaps_internal(ek, m)
+}
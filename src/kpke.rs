@@ -1,33 +1,185 @@
-use crate::{SELECTED_PARAMETER_SET, algebraic::{ntt, sample_ntt, sample_poly_cbd}, cryptographic::{g, prf}, polynomial::Poly16};
+use crate::{N, algebraic::{multiply_ntts_i16, ntt_i16, ntt_inv_i16, sample_ntt, sample_poly_cbd}, auxiliary::{byte_decode, byte_encode, compress, decompress}, cryptographic::{g, prf}, params::{ByteArray, ParameterSet}, polynomial::PolyI16};
 
-pub fn key_gen(d: [u8;32]){
-    let (rho, sigma) = g(&d);
-    let n = 0;
+// Largest k over all parameter sets, used to size the per-call polynomial vectors
+const MAX_K: usize = 4;
 
-    // TODO: Properly initialize as null
-    let mut a = [[Poly16::new(&[0;SELECTED_PARAMETER_SET.n as usize]); SELECTED_PARAMETER_SET.k as usize];SELECTED_PARAMETER_SET.k as usize];
-    let mut s = [Poly16::new(&[0;SELECTED_PARAMETER_SET.n as usize]);SELECTED_PARAMETER_SET.k as usize];
-    let mut e = [Poly16::new(&[0;SELECTED_PARAMETER_SET.n as usize]);SELECTED_PARAMETER_SET.k as usize];
+// Polynomials are kept as signed i16 and only reduced where a bound requires it.
+// Every product or NTT output is in (-q, q), so sums of up to K + 1 of them fit.
+fn zero() -> PolyI16 {
+    PolyI16::new(&[0; N])
+}
 
-    for i in 0..SELECTED_PARAMETER_SET.k as usize{
-        for j in 0..SELECTED_PARAMETER_SET.k as usize {
-            a[i][j] = sample_ntt(rho, j as u8, i as u8);
+// η only ever takes the values 2 and 3, so the const-generic samplers are picked here
+fn sample_cbd(eta: usize, s: [u8; 32], b: u8) -> PolyI16 {
+    match eta {
+        2 => sample_poly_cbd::<2>(prf::<2>(s, b)).into(),
+        3 => sample_poly_cbd::<3>(prf::<3>(s, b)).into(),
+        _ => unreachable!("unsupported eta {eta}"),
+    }
+}
+
+// ByteEncode_d(Compress_d(f)) for the d values used by the ciphertext
+fn compress_encode(d: usize, f: PolyI16, out: &mut [u8]) {
+    let f = f.to_canonical();
+    match d {
+        1 => byte_encode::<1, 32>(compress::<1>(f), out.try_into().unwrap()),
+        4 => byte_encode::<4, 128>(compress::<4>(f), out.try_into().unwrap()),
+        5 => byte_encode::<5, 160>(compress::<5>(f), out.try_into().unwrap()),
+        10 => byte_encode::<10, 320>(compress::<10>(f), out.try_into().unwrap()),
+        11 => byte_encode::<11, 352>(compress::<11>(f), out.try_into().unwrap()),
+        _ => unreachable!("unsupported d {d}"),
+    }
+}
+
+// Decompress_d(ByteDecode_d(bytes)) for the d values used by the ciphertext
+fn decode_decompress(d: usize, bytes: &[u8]) -> PolyI16 {
+    let f = match d {
+        1 => decompress::<1>(byte_decode::<1, 32>(bytes.try_into().unwrap())),
+        4 => decompress::<4>(byte_decode::<4, 128>(bytes.try_into().unwrap())),
+        5 => decompress::<5>(byte_decode::<5, 160>(bytes.try_into().unwrap())),
+        10 => decompress::<10>(byte_decode::<10, 320>(bytes.try_into().unwrap())),
+        11 => decompress::<11>(byte_decode::<11, 352>(bytes.try_into().unwrap())),
+        _ => unreachable!("unsupported d {d}"),
+    };
+    f.into()
+}
+
+fn sample_matrix<P: ParameterSet>(rho: [u8; 32]) -> [[PolyI16; MAX_K]; MAX_K] {
+    let mut a = [[zero(); MAX_K]; MAX_K];
+
+    for (i, row) in a.iter_mut().enumerate().take(P::K) {
+        for (j, a_ij) in row.iter_mut().enumerate().take(P::K) {
+            *a_ij = sample_ntt(rho, j as u8, i as u8).into();
         }
     }
 
-    for i in 0..SELECTED_PARAMETER_SET.k as usize {
-        let v = prf::<{ SELECTED_PARAMETER_SET.eta1 }>(sigma, n);
-        s[i] = sample_poly_cbd::<{ SELECTED_PARAMETER_SET.eta1 }>(v);
+    a
+}
+
+pub fn key_gen<P: ParameterSet>(d: [u8;32]) -> (P::EncapsulationKeyBytes, P::DecryptionKeyBytes) {
+    let mut seed = [0u8; 33];
+    seed[..32].copy_from_slice(&d);
+    seed[32] = P::K as u8;
+
+    let (rho, sigma) = g(&seed);
+    let mut n = 0;
+
+    let a = sample_matrix::<P>(rho);
+    let mut s = [zero(); MAX_K];
+    let mut e = [zero(); MAX_K];
+
+    for s_i in s.iter_mut().take(P::K) {
+        *s_i = sample_cbd(P::ETA1, sigma, n);
+        n += 1;
+    }
+
+    for e_i in e.iter_mut().take(P::K) {
+        *e_i = sample_cbd(P::ETA1, sigma, n);
+        n += 1;
+    }
+
+    let s_ntt = s.map(ntt_i16);
+    let e_ntt = e.map(ntt_i16);
+
+    // t̂ = Â∘ŝ + ê
+    let mut t_ntt = e_ntt;
+    for i in 0..P::K {
+        for j in 0..P::K {
+            t_ntt[i] = t_ntt[i] + multiply_ntts_i16(a[i][j], s_ntt[j]);
+        }
+    }
+
+    let mut ek = P::EncapsulationKeyBytes::zeroed();
+    let mut dk = P::DecryptionKeyBytes::zeroed();
+
+    let (ek_chunks, ek_rho) = ek.as_mut().as_chunks_mut::<384>();
+    let (dk_chunks, _) = dk.as_mut().as_chunks_mut::<384>();
+    for i in 0..P::K {
+        byte_encode::<12, 384>(t_ntt[i].to_canonical(), &mut ek_chunks[i]);
+        byte_encode::<12, 384>(s_ntt[i].to_canonical(), &mut dk_chunks[i]);
+    }
+    ek_rho.copy_from_slice(&rho);
+
+    (ek, dk)
+}
+
+pub fn encrypt<P: ParameterSet>(ek: &P::EncapsulationKeyBytes, m: [u8; 32], r: [u8; 32]) -> P::CiphertextBytes {
+    let mut n = 0;
+
+    let (ek_chunks, ek_rho) = ek.as_ref().as_chunks::<384>();
+    let mut t_ntt = [zero(); MAX_K];
+    for i in 0..P::K {
+        t_ntt[i] = byte_decode::<12, 384>(&ek_chunks[i]).into();
+    }
+    let rho: [u8; 32] = ek_rho.try_into().unwrap();
+
+    let a = sample_matrix::<P>(rho);
+    let mut y = [zero(); MAX_K];
+    let mut e1 = [zero(); MAX_K];
+
+    for y_i in y.iter_mut().take(P::K) {
+        *y_i = sample_cbd(P::ETA1, r, n);
+        n += 1;
+    }
+
+    for e1_i in e1.iter_mut().take(P::K) {
+        *e1_i = sample_cbd(P::ETA2, r, n);
+        n += 1;
+    }
+
+    let e2 = sample_cbd(P::ETA2, r, n);
+
+    let y_ntt = y.map(ntt_i16);
+
+    // u = NTT⁻¹(Âᵀ∘ŷ) + e1
+    let mut u = e1;
+    for i in 0..P::K {
+        let mut acc = zero();
+        for j in 0..P::K {
+            acc = acc + multiply_ntts_i16(a[j][i], y_ntt[j]);
+        }
+        u[i] = u[i] + ntt_inv_i16(acc);
+    }
+
+    // v = NTT⁻¹(t̂ᵀ∘ŷ) + e2 + μ
+    let mu: PolyI16 = decompress::<1>(byte_decode::<1, 32>(&m)).into();
+    let mut acc = zero();
+    for j in 0..P::K {
+        acc = acc + multiply_ntts_i16(t_ntt[j], y_ntt[j]);
+    }
+    let v = ntt_inv_i16(acc) + e2 + mu;
+
+    let mut c = P::CiphertextBytes::zeroed();
+    let (c1, c2) = c.as_mut().split_at_mut(32*P::DU*P::K);
+    for (i, chunk) in c1.chunks_exact_mut(32*P::DU).enumerate() {
+        compress_encode(P::DU, u[i], chunk);
+    }
+    compress_encode(P::DV, v, c2);
+
+    c
+}
+
+pub fn decrypt<P: ParameterSet>(dk: &P::DecryptionKeyBytes, c: &P::CiphertextBytes) -> [u8; 32] {
+    let mut u = [zero(); MAX_K];
+    let mut s_ntt = [zero(); MAX_K];
+
+    let (c1, c2) = c.as_ref().split_at(32*P::DU*P::K);
+    let (dk_chunks, _) = dk.as_ref().as_chunks::<384>();
+    for (i, chunk) in c1.chunks_exact(32*P::DU).enumerate() {
+        u[i] = decode_decompress(P::DU, chunk);
+        s_ntt[i] = byte_decode::<12, 384>(&dk_chunks[i]).into();
     }
+    let v = decode_decompress(P::DV, c2);
 
-    for i in 0..SELECTED_PARAMETER_SET.k as usize {
-        let v = prf::<{ SELECTED_PARAMETER_SET.eta1 }>(sigma, n);
-        e[i] = sample_poly_cbd::<{ SELECTED_PARAMETER_SET.eta1 }>(v);
+    // w = v′ − NTT⁻¹(ŝᵀ∘NTT(u′))
+    let mut acc = zero();
+    for i in 0..P::K {
+        acc = acc + multiply_ntts_i16(s_ntt[i], ntt_i16(u[i]));
     }
+    let w = v - ntt_inv_i16(acc);
 
-    let s_ntt= s.map(|c| ntt(c));
-    let e_ntt = s.map(|c| ntt(c));
+    let mut m = [0u8; 32];
+    byte_encode::<1, 32>(compress::<1>(w.to_canonical()), &mut m);
 
-    println!("{:?}", s_ntt);
-    println!("{:?}", e_ntt);
-}
\ No newline at end of file
+    m
+}
@@ -1,13 +1,35 @@
 // Library interface for ml-kem-rs to enable testing
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod cryptographic;
 pub mod kpke;
+pub mod ml_kem;
+pub mod any;
+pub mod params;
 pub mod algebraic;
 pub mod auxiliary;
+pub mod reduce;
 pub mod polynomial;
 pub mod bytevec;
+pub mod error;
+pub mod rng;
+#[cfg(feature = "hazmat")]
+pub mod hazmat;
+
+pub use params::{MlKem512, MlKem768, MlKem1024};
+pub use any::AnyMlKem;
+pub use error::Error;
+
+pub const N: usize = 256;
+pub const Q: u32 = 3329;
 
-pub struct ParameterSet {
+pub struct ParameterSetConstants {
     pub n: usize,
     pub k: u32,
     pub q: u32,
@@ -17,8 +39,25 @@ pub struct ParameterSet {
     pub dv: u32
 }
 
-pub const ML_KEM_512: ParameterSet = ParameterSet { n: 256, k: 2, q: 3329, eta1: 3, eta2: 2, du: 10, dv: 4 };
-pub const ML_KEM_768: ParameterSet = ParameterSet { n: 256, k: 3, q: 3329, eta1: 2, eta2: 2, du: 10, dv: 4 };
-pub const ML_KEM_1024: ParameterSet = ParameterSet { n: 256, k: 4, q: 3329, eta1: 2, eta2: 2, du: 11, dv: 5 };
+// Encoded sizes in bytes, FIPS 203 Table 3
+impl ParameterSetConstants {
+    pub const fn encapsulation_key_len(&self) -> usize {
+        384*self.k as usize + 32
+    }
+
+    pub const fn decapsulation_key_len(&self) -> usize {
+        768*self.k as usize + 96
+    }
+
+    pub const fn ciphertext_len(&self) -> usize {
+        32*(self.du*self.k + self.dv) as usize
+    }
+
+    pub const fn shared_secret_len(&self) -> usize {
+        32
+    }
+}
 
-pub const SELECTED_PARAMETER_SET: &ParameterSet = &ML_KEM_768;
+pub const ML_KEM_512: ParameterSetConstants = ParameterSetConstants { n: N, k: 2, q: Q, eta1: 3, eta2: 2, du: 10, dv: 4 };
+pub const ML_KEM_768: ParameterSetConstants = ParameterSetConstants { n: N, k: 3, q: Q, eta1: 2, eta2: 2, du: 10, dv: 4 };
+pub const ML_KEM_1024: ParameterSetConstants = ParameterSetConstants { n: N, k: 4, q: Q, eta1: 2, eta2: 2, du: 11, dv: 5 };
@@ -0,0 +1,340 @@
+use core::fmt;
+
+use rand_core::CryptoRngCore;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::{auxiliary::{byte_decode, byte_encode}, cryptographic::{g, h, j_concat}, error::Error, kpke, params::{ByteArray, ParameterSet}, rng::random_seed};
+#[cfg(feature = "getrandom")]
+use crate::rng::OsRng;
+
+#[derive(Copy, Clone)]
+pub struct SharedSecret([u8; 32]);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EncapsulationKey<P: ParameterSet>(P::EncapsulationKeyBytes);
+
+// The (d, z) seed is only known for keys generated here or loaded with from_seed
+#[derive(Copy, Clone)]
+pub struct DecapsulationKey<P: ParameterSet> {
+    bytes: P::DecapsulationKeyBytes,
+    seed: Option<[u8; 64]>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext<P: ParameterSet>(P::CiphertextBytes);
+
+impl<P: ParameterSet> EncapsulationKey<P> {
+    pub const LEN: usize = P::ENCAPSULATION_KEY_LEN;
+
+    pub fn as_bytes(&self) -> &P::EncapsulationKeyBytes {
+        &self.0
+    }
+}
+
+// ByteEncode12(ByteDecode12(t̂)) == t̂, i.e. every encoded coefficient is below q
+fn modulus_check(t_hat: &[u8]) -> bool {
+    let (chunks, _) = t_hat.as_chunks::<384>();
+    let mut encoded = [0u8; 384];
+    for chunk in chunks {
+        byte_encode::<12, 384>(byte_decode::<12, 384>(chunk), &mut encoded);
+        if encoded != *chunk {
+            return false;
+        }
+    }
+    true
+}
+
+// FIPS 203 §7.2: length check and ByteEncode12(ByteDecode12(ek)) == ek
+impl<P: ParameterSet> TryFrom<&[u8]> for EncapsulationKey<P> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::LEN {
+            return Err(Error::InvalidLength { expected: Self::LEN, actual: bytes.len() });
+        }
+
+        if !modulus_check(&bytes[..384*P::K]) {
+            return Err(Error::InvalidEncapsulationKey);
+        }
+
+        let mut ek = P::EncapsulationKeyBytes::zeroed();
+        ek.as_mut().copy_from_slice(bytes);
+        Ok(EncapsulationKey(ek))
+    }
+}
+
+impl<P: ParameterSet> DecapsulationKey<P> {
+    pub const LEN: usize = P::DECAPSULATION_KEY_LEN;
+
+    // Expands seed = d || z with KeyGen_internal(d, z)
+    pub fn from_seed(seed: [u8; 64]) -> Self {
+        let mut d = [0u8; 32];
+        let mut z = [0u8; 32];
+        d.copy_from_slice(&seed[..32]);
+        z.copy_from_slice(&seed[32..]);
+        let (_, dk) = keygen_internal::<P>(d, z);
+        dk
+    }
+
+    pub fn to_seed(&self) -> Option<[u8; 64]> {
+        self.seed
+    }
+
+    pub fn encapsulation_key(&self) -> EncapsulationKey<P> {
+        let mut ek = P::EncapsulationKeyBytes::zeroed();
+        ek.as_mut().copy_from_slice(&self.bytes.as_ref()[384*P::K..768*P::K + 32]);
+        EncapsulationKey(ek)
+    }
+
+    pub fn as_bytes(&self) -> &P::DecapsulationKeyBytes {
+        &self.bytes
+    }
+}
+
+// Secret material is never formatted, only the parameter set
+impl<P: ParameterSet> fmt::Debug for DecapsulationKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DecapsulationKey<{}> {{ .. }}", parameter_set_name::<P>())
+    }
+}
+
+// "MlKem768" rather than the full path of the marker type
+fn parameter_set_name<P: ParameterSet>() -> &'static str {
+    let name = core::any::type_name::<P>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// Keys are equal when their expanded forms are, whether or not the seed is known.
+// Compared in constant time, like SharedSecret.
+impl<P: ParameterSet> PartialEq for DecapsulationKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes.as_ref().ct_eq(other.bytes.as_ref()).into()
+    }
+}
+
+impl<P: ParameterSet> Eq for DecapsulationKey<P> {}
+
+// FIPS 203 §7.3: length check and H(dk[384k : 768k + 32]) == dk[768k + 32 : 768k + 64].
+// The embedded ek also gets the §7.2 modulus check, since encapsulation_key() hands it out.
+impl<P: ParameterSet> TryFrom<&[u8]> for DecapsulationKey<P> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::LEN {
+            return Err(Error::InvalidLength { expected: Self::LEN, actual: bytes.len() });
+        }
+
+        let ek = &bytes[384*P::K..768*P::K + 32];
+        let h_ek = &bytes[768*P::K + 32..768*P::K + 64];
+        if h(ek) != h_ek || !modulus_check(&ek[..384*P::K]) {
+            return Err(Error::InvalidDecapsulationKey);
+        }
+
+        let mut dk = P::DecapsulationKeyBytes::zeroed();
+        dk.as_mut().copy_from_slice(bytes);
+        Ok(DecapsulationKey { bytes: dk, seed: None })
+    }
+}
+
+impl<P: ParameterSet> TryFrom<&[u8]> for Ciphertext<P> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::LEN {
+            return Err(Error::InvalidLength { expected: Self::LEN, actual: bytes.len() });
+        }
+
+        let mut c = P::CiphertextBytes::zeroed();
+        c.as_mut().copy_from_slice(bytes);
+        Ok(Ciphertext(c))
+    }
+}
+
+impl<P: ParameterSet> Ciphertext<P> {
+    pub const LEN: usize = P::CIPHERTEXT_LEN;
+
+    // A blanket From<P::CiphertextBytes> would overlap with From<T> for T
+    pub fn new(bytes: P::CiphertextBytes) -> Self {
+        Ciphertext(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &P::CiphertextBytes {
+        &self.0
+    }
+}
+
+impl SharedSecret {
+    pub const LEN: usize = 32;
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedSecret { .. }")
+    }
+}
+
+impl From<[u8; 32]> for SharedSecret {
+    fn from(bytes: [u8; 32]) -> Self {
+        SharedSecret(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for SharedSecret {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| Error::InvalidLength { expected: Self::LEN, actual: bytes.len() })?;
+        Ok(SharedSecret(bytes))
+    }
+}
+
+impl ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for SharedSecret {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        SharedSecret(<[u8; 32]>::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+// Compared in constant time, so equality checks on secrets do not leak a prefix
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SharedSecret {}
+
+#[cfg(feature = "getrandom")]
+pub fn keygen<P: ParameterSet>() -> Result<(EncapsulationKey<P>, DecapsulationKey<P>), Error> {
+    keygen_with_rng(&mut OsRng)
+}
+
+pub fn keygen_with_rng<P: ParameterSet>(rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(EncapsulationKey<P>, DecapsulationKey<P>), Error> {
+    let d = random_seed(rng)?;
+    let z = random_seed(rng)?;
+
+    Ok(keygen_internal(d, z))
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyGenOptions {
+    // Run the FIPS 140-3 pairwise consistency test before returning the keypair
+    pub pairwise_consistency_test: bool,
+}
+
+#[cfg(feature = "getrandom")]
+pub fn keygen_with_options<P: ParameterSet>(options: KeyGenOptions) -> Result<(EncapsulationKey<P>, DecapsulationKey<P>), Error> {
+    keygen_with_options_and_rng(options, &mut OsRng)
+}
+
+pub fn keygen_with_options_and_rng<P: ParameterSet>(options: KeyGenOptions, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(EncapsulationKey<P>, DecapsulationKey<P>), Error> {
+    let (ek, dk) = keygen_with_rng::<P>(rng)?;
+
+    if options.pairwise_consistency_test {
+        pairwise_consistency_test_with_rng(&ek, &dk, rng)?;
+    }
+
+    Ok((ek, dk))
+}
+
+#[cfg(feature = "getrandom")]
+pub fn pairwise_consistency_test<P: ParameterSet>(ek: &EncapsulationKey<P>, dk: &DecapsulationKey<P>) -> Result<(), Error> {
+    pairwise_consistency_test_with_rng(ek, dk, &mut OsRng)
+}
+
+// Encaps to ek and decaps with dk, the shared secrets must agree
+pub fn pairwise_consistency_test_with_rng<P: ParameterSet>(ek: &EncapsulationKey<P>, dk: &DecapsulationKey<P>, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(), Error> {
+    let (shared_secret, c) = encaps_with_rng(ek, rng)?;
+
+    if bool::from(decaps(dk, &c).ct_eq(&shared_secret)) {
+        Ok(())
+    } else {
+        Err(Error::PairwiseConsistencyTestFailed)
+    }
+}
+
+#[cfg(feature = "getrandom")]
+pub fn encaps<P: ParameterSet>(ek: &EncapsulationKey<P>) -> Result<(SharedSecret, Ciphertext<P>), Error> {
+    encaps_with_rng(ek, &mut OsRng)
+}
+
+pub fn encaps_with_rng<P: ParameterSet>(ek: &EncapsulationKey<P>, rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<(SharedSecret, Ciphertext<P>), Error> {
+    let m = random_seed(rng)?;
+
+    Ok(encaps_internal(ek, m))
+}
+
+pub fn decaps<P: ParameterSet>(dk: &DecapsulationKey<P>, c: &Ciphertext<P>) -> SharedSecret {
+    decaps_internal(dk, c)
+}
+
+pub(crate) fn keygen_internal<P: ParameterSet>(d: [u8; 32], z: [u8; 32]) -> (EncapsulationKey<P>, DecapsulationKey<P>) {
+    let (ek_pke, dk_pke) = kpke::key_gen::<P>(d);
+    let dk_pke_len = dk_pke.as_ref().len();
+    let ek_len = ek_pke.as_ref().len();
+
+    // dk = dkPKE || ek || H(ek) || z
+    let mut dk = P::DecapsulationKeyBytes::zeroed();
+    let bytes = dk.as_mut();
+    let dk_len = bytes.len();
+    bytes[..dk_pke_len].copy_from_slice(dk_pke.as_ref());
+    bytes[dk_pke_len..dk_pke_len + ek_len].copy_from_slice(ek_pke.as_ref());
+    bytes[dk_pke_len + ek_len..dk_len - 32].copy_from_slice(&h(ek_pke.as_ref()));
+    bytes[dk_len - 32..].copy_from_slice(&z);
+
+    let mut seed = [0u8; 64];
+    seed[..32].copy_from_slice(&d);
+    seed[32..].copy_from_slice(&z);
+
+    (EncapsulationKey(ek_pke), DecapsulationKey { bytes: dk, seed: Some(seed) })
+}
+
+pub(crate) fn encaps_internal<P: ParameterSet>(ek: &EncapsulationKey<P>, m: [u8; 32]) -> (SharedSecret, Ciphertext<P>) {
+    let mut seed = [0u8; 64];
+    seed[..32].copy_from_slice(&m);
+    seed[32..].copy_from_slice(&h(ek.0.as_ref()));
+
+    let (shared_secret, r) = g(&seed);
+    let c = kpke::encrypt::<P>(&ek.0, m, r);
+
+    (SharedSecret(shared_secret), Ciphertext(c))
+}
+
+fn decaps_internal<P: ParameterSet>(dk: &DecapsulationKey<P>, c: &Ciphertext<P>) -> SharedSecret {
+    let mut dk_pke = P::DecryptionKeyBytes::zeroed();
+    let mut ek_pke = P::EncapsulationKeyBytes::zeroed();
+    let dk_pke_len = dk_pke.as_ref().len();
+    let ek_len = ek_pke.as_ref().len();
+
+    let bytes = dk.bytes.as_ref();
+    let dk_len = bytes.len();
+    dk_pke.as_mut().copy_from_slice(&bytes[..dk_pke_len]);
+    ek_pke.as_mut().copy_from_slice(&bytes[dk_pke_len..dk_pke_len + ek_len]);
+    let h_ek = &bytes[dk_pke_len + ek_len..dk_len - 32];
+    let z = &bytes[dk_len - 32..];
+
+    let m = kpke::decrypt::<P>(&dk_pke, &c.0);
+
+    let mut seed = [0u8; 64];
+    seed[..32].copy_from_slice(&m);
+    seed[32..].copy_from_slice(h_ek);
+    let (shared_secret, r) = g(&seed);
+
+    // K̄ = J(z || c)
+    let rejection_secret = j_concat(z, c.0.as_ref());
+
+    // Re-encrypt and pick K′ or K̄ without branching on the comparison
+    let c_prime = kpke::encrypt::<P>(&ek_pke, m, r);
+    let valid = c.0.as_ref().ct_eq(c_prime.as_ref());
+
+    SharedSecret(<[u8; 32]>::conditional_select(&rejection_secret, &shared_secret, valid))
+}
@@ -0,0 +1,80 @@
+use core::fmt::Debug;
+
+use crate::{ML_KEM_512, ML_KEM_768, ML_KEM_1024};
+
+// Fixed-size byte buffer used for encoded keys and ciphertexts
+pub trait ByteArray: Copy + Debug + Eq + AsRef<[u8]> + AsMut<[u8]> {
+    fn zeroed() -> Self;
+}
+
+impl<const L: usize> ByteArray for [u8; L] {
+    fn zeroed() -> Self {
+        [0u8; L]
+    }
+}
+
+mod sealed {
+    // Only the three FIPS 203 parameter sets may implement ParameterSet, since
+    // kpke indexes fixed-size buffers with k and only handles the standard η, du and dv
+    pub trait Sealed {}
+
+    impl Sealed for super::MlKem512 {}
+    impl Sealed for super::MlKem768 {}
+    impl Sealed for super::MlKem1024 {}
+}
+
+// Type-level counterpart of the ParameterSetConstants values, so that one build can use every set
+pub trait ParameterSet: sealed::Sealed + Copy + Debug + Eq + 'static {
+    const PARAMETERS: crate::ParameterSetConstants;
+
+    const K: usize = Self::PARAMETERS.k as usize;
+    const ETA1: usize = Self::PARAMETERS.eta1;
+    const ETA2: usize = Self::PARAMETERS.eta2;
+    const DU: usize = Self::PARAMETERS.du as usize;
+    const DV: usize = Self::PARAMETERS.dv as usize;
+
+    const ENCAPSULATION_KEY_LEN: usize = Self::PARAMETERS.encapsulation_key_len();
+    const DECAPSULATION_KEY_LEN: usize = Self::PARAMETERS.decapsulation_key_len();
+    const CIPHERTEXT_LEN: usize = Self::PARAMETERS.ciphertext_len();
+
+    type EncapsulationKeyBytes: ByteArray;
+    type DecryptionKeyBytes: ByteArray;
+    type DecapsulationKeyBytes: ByteArray;
+    type CiphertextBytes: ByteArray;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MlKem512;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MlKem768;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MlKem1024;
+
+impl ParameterSet for MlKem512 {
+    const PARAMETERS: crate::ParameterSetConstants = ML_KEM_512;
+
+    type EncapsulationKeyBytes = [u8; ML_KEM_512.encapsulation_key_len()];
+    type DecryptionKeyBytes = [u8; 384*ML_KEM_512.k as usize];
+    type DecapsulationKeyBytes = [u8; ML_KEM_512.decapsulation_key_len()];
+    type CiphertextBytes = [u8; ML_KEM_512.ciphertext_len()];
+}
+
+impl ParameterSet for MlKem768 {
+    const PARAMETERS: crate::ParameterSetConstants = ML_KEM_768;
+
+    type EncapsulationKeyBytes = [u8; ML_KEM_768.encapsulation_key_len()];
+    type DecryptionKeyBytes = [u8; 384*ML_KEM_768.k as usize];
+    type DecapsulationKeyBytes = [u8; ML_KEM_768.decapsulation_key_len()];
+    type CiphertextBytes = [u8; ML_KEM_768.ciphertext_len()];
+}
+
+impl ParameterSet for MlKem1024 {
+    const PARAMETERS: crate::ParameterSetConstants = ML_KEM_1024;
+
+    type EncapsulationKeyBytes = [u8; ML_KEM_1024.encapsulation_key_len()];
+    type DecryptionKeyBytes = [u8; 384*ML_KEM_1024.k as usize];
+    type DecapsulationKeyBytes = [u8; ML_KEM_1024.decapsulation_key_len()];
+    type CiphertextBytes = [u8; ML_KEM_1024.ciphertext_len()];
+}
@@ -1,13 +1,16 @@
-use std::ops::{Add, Index, IndexMut, Mul, Rem};
-use crate::SELECTED_PARAMETER_SET;
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+use crate::{N, reduce::{barrett_reduce, barrett_reduce_u16, canonical, fqmul, to_montgomery}};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Polynomial<T: num_traits::PrimInt, const N: usize>([T; N]);
 
-pub type Poly16 = Polynomial<u16, {SELECTED_PARAMETER_SET.n}>;
-pub type Poly32 = Polynomial<u32, {SELECTED_PARAMETER_SET.n}>;
+pub type Poly16 = Polynomial<u16, {N}>;
+pub type Poly32 = Polynomial<u32, {N}>;
+// Signed coefficients, used by the arithmetic in K-PKE. Any i16 is a valid
+// representative of its class mod q, so sums can be reduced lazily.
+pub type PolyI16 = Polynomial<i16, {N}>;
 
-impl<T: num_traits::PrimInt, const N: usize, Idx: std::slice::SliceIndex<[T]>> Index<Idx> for Polynomial<T, N> {
+impl<T: num_traits::PrimInt, const N: usize, Idx: core::slice::SliceIndex<[T]>> Index<Idx> for Polynomial<T, N> {
     type Output = Idx::Output;
 
     fn index(&self, index: Idx) -> &Self::Output {
@@ -15,51 +18,53 @@ impl<T: num_traits::PrimInt, const N: usize, Idx: std::slice::SliceIndex<[T]>> I
     }
 }
 
-impl<T: num_traits::PrimInt, const N: usize, Idx: std::slice::SliceIndex<[T]>> IndexMut<Idx> for Polynomial<T, N> {
+impl<T: num_traits::PrimInt, const N: usize, Idx: core::slice::SliceIndex<[T]>> IndexMut<Idx> for Polynomial<T, N> {
     fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
         &mut self.0[index] 
     }
 }
 
-impl<T: num_traits::PrimInt, const N: usize> Add for Polynomial<T, N> {
+impl<T: num_traits::PrimInt + num_traits::WrappingAdd, const N: usize> Add for Polynomial<T, N> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         let mut coeffs: [T; N] = [T::zero(); N];
 
         for ((coefref, selfvalue), othervalue) in coeffs.iter_mut().zip(&self.0).zip(&other.0) {
-            *coefref = *selfvalue + *othervalue;
+            *coefref = selfvalue.wrapping_add(othervalue);
         }
 
-        return Polynomial::new(&coeffs);
+        Polynomial::new(&coeffs)
     }
 }
 
-impl<T: num_traits::PrimInt, const N: usize> Mul<T> for Polynomial<T, N>{
-    type Output = Polynomial<T, N>;
+impl<T: num_traits::PrimInt + num_traits::WrappingSub, const N: usize> Sub for Polynomial<T, N> {
+    type Output = Self;
 
-    fn mul(self, rhs: T) -> Self::Output {
-        let mut output: [T;N] = [T::zero();N];
+    fn sub(self, other: Self) -> Self {
+        let mut coeffs: [T; N] = [T::zero(); N];
 
-        for i in 0..N {
-            output[i] = self[i] * rhs;
+        for ((coefref, selfvalue), othervalue) in coeffs.iter_mut().zip(&self.0).zip(&other.0) {
+            *coefref = selfvalue.wrapping_sub(othervalue);
         }
 
-        Polynomial::new(&output)
+        Polynomial::new(&coeffs)
     }
 }
 
-impl<T: num_traits::PrimInt, const N: usize> Rem<T> for Polynomial<T, N>{
-    type Output = Polynomial<T, N>;
-    
-    fn rem(self, rhs: T) -> Self::Output {
-        let mut output: [T;N] = [T::zero();N];
+// Scalar multiplication mod q; output coefficients in (-q, q)
+impl<const N: usize> Mul<i16> for Polynomial<i16, N> {
+    type Output = Self;
 
-        for i in 0..N {
-            output[i] = self[i] % rhs;
+    fn mul(self, rhs: i16) -> Self {
+        let rhs = to_montgomery(rhs);
+        let mut coeffs = [0i16; N];
+
+        for (coeff, &a) in coeffs.iter_mut().zip(&self.0) {
+            *coeff = fqmul(a, rhs);
         }
 
-        Polynomial::new(&output)
+        Polynomial::new(&coeffs)
     }
 }
 
@@ -79,9 +84,52 @@ impl<T: num_traits::PrimInt, const N: usize> IntoIterator for Polynomial<T, N> {
 
 impl<T: num_traits::PrimInt, const N: usize> Polynomial<T, N> {
     pub fn new(coefficients: &[T;N]) -> Polynomial<T, N> {
-        return Polynomial { 0: coefficients.clone() }
+        Polynomial(*coefficients)
     }
 }
+impl<const N: usize> Polynomial<i16, N> {
+    // Centered representatives in [-(q-1)/2, (q-1)/2]
+    pub fn reduce(self) -> Self {
+        let mut coeffs = [0i16; N];
+
+        for i in 0..N {
+            coeffs[i] = barrett_reduce(self[i]);
+        }
+
+        Polynomial::new(&coeffs)
+    }
+
+    // Canonical representatives in [0, q), as expected by ByteEncode and Compress
+    pub fn to_canonical(self) -> Polynomial<u16, N> {
+        let mut coeffs = [0u16; N];
+
+        for i in 0..N {
+            coeffs[i] = canonical(self[i]);
+        }
+
+        Polynomial::new(&coeffs)
+    }
+}
+
+// Coefficients must be in [0, q), as produced by ByteDecode and the samplers
+impl<const N: usize> From<Polynomial<u16, N>> for Polynomial<i16, N> {
+    fn from(f: Polynomial<u16, N>) -> Self {
+        let mut coeffs = [0i16; N];
+
+        for i in 0..N {
+            coeffs[i] = barrett_reduce_u16(f[i]);
+        }
+
+        Polynomial::new(&coeffs)
+    }
+}
+
+impl<const N: usize> From<Polynomial<i16, N>> for Polynomial<u16, N> {
+    fn from(f: Polynomial<i16, N>) -> Self {
+        f.to_canonical()
+    }
+}
+
 pub struct PolynomialIterator<T: num_traits::PrimInt, const N: usize> {
     polynomial: Polynomial<T, N>,
     index: usize
@@ -91,10 +139,11 @@ impl<T: num_traits::PrimInt, const N: usize> Iterator for PolynomialIterator<T,
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index <= N {
-            return Some(self.polynomial[self.index]);
+        if self.index < N {
+            self.index += 1;
+            Some(self.polynomial[self.index - 1])
         } else {
-            return None;
+            None
         }
     }
 }
@@ -0,0 +1,59 @@
+use crate::Q;
+
+// Modular reduction without division, following the Kyber reference implementation.
+// A value a is in Montgomery form when it is stored as a·R mod q, with R = 2^16.
+
+const QI: i32 = Q as i32;
+
+// q^-1 mod 2^16, as a signed 16-bit value
+const QINV: i16 = -3327;
+
+// R^2 mod q, so that montgomery_reduce(a·R2) = a·R mod q
+pub const R2: i16 = 1353;
+
+// a·R^-1 mod q in (-q, q), for |a| < q·2^15
+pub const fn montgomery_reduce(a: i32) -> i16 {
+    let t = (a as i16).wrapping_mul(QINV);
+    ((a - t as i32 * QI) >> 16) as i16
+}
+
+// a mod q in [-(q-1)/2, (q-1)/2], for any i16
+pub const fn barrett_reduce(a: i16) -> i16 {
+    // round(2^26 / q)
+    const V: i32 = ((1 << 26) + QI / 2) / QI;
+
+    // t·q can leave the i16 range near i16::MIN even though the result fits
+    let t = (V * a as i32 + (1 << 25)) >> 26;
+    (a as i32 - t * QI) as i16
+}
+
+// a mod q in [-(q-1)/2, (q-1)/2], for any u16
+pub const fn barrett_reduce_u16(a: u16) -> i16 {
+    // Subtracting 10q when a >= 2^15 brings it into the i16 range without a branch
+    barrett_reduce((a as i32 - (a >> 15) as i32 * 10 * QI) as i16)
+}
+
+// a·b·R^-1 mod q; with b in Montgomery form this is a·b mod q
+pub const fn fqmul(a: i16, b: i16) -> i16 {
+    montgomery_reduce(a as i32 * b as i32)
+}
+
+// a·R mod q in (-q, q)
+pub const fn to_montgomery(a: i16) -> i16 {
+    fqmul(a, R2)
+}
+
+// Adds q if a is negative, without branching
+pub const fn caddq(a: i16) -> i16 {
+    a + ((a >> 15) & Q as i16)
+}
+
+// Subtracts q if a >= q, without branching
+pub const fn csubq(a: i16) -> i16 {
+    caddq(a - Q as i16)
+}
+
+// Canonical representative in [0, q) of any i16
+pub const fn canonical(a: i16) -> u16 {
+    caddq(barrett_reduce(a)) as u16
+}
@@ -0,0 +1,54 @@
+use rand_core::CryptoRngCore;
+
+use crate::error::Error;
+
+#[cfg(feature = "getrandom")]
+pub use os::OsRng;
+
+// 32 fresh bytes, with any RNG failure reported as Error::RngFailure
+pub(crate) fn random_seed(rng: &mut (impl CryptoRngCore + ?Sized)) -> Result<[u8; 32], Error> {
+    let mut seed = [0u8; 32];
+    rng.try_fill_bytes(&mut seed).map_err(|_| Error::RngFailure)?;
+    Ok(seed)
+}
+
+#[cfg(feature = "getrandom")]
+mod os {
+    use core::num::NonZeroU32;
+
+    use rand_core::{CryptoRng, RngCore};
+
+    // Operating system RNG through getrandom, used by keygen and encaps when no RNG is passed in
+    #[derive(Copy, Clone, Debug, Default)]
+    pub struct OsRng;
+
+    // getrandom errors without an OS error code are reported with rand_core's first custom code
+    const UNKNOWN_ERROR: NonZeroU32 = NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap();
+
+    impl RngCore for OsRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        // The RngCore contract has no way to report failure here. This crate only
+        // calls try_fill_bytes, so this panic is only reachable from other callers.
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            if let Err(e) = self.try_fill_bytes(dest) {
+                panic!("OS random number generator failed: {e}");
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            getrandom::fill(dest).map_err(|e| {
+                let code = e.raw_os_error().and_then(|code| NonZeroU32::new(code as u32));
+                rand_core::Error::from(code.unwrap_or(UNKNOWN_ERROR))
+            })
+        }
+    }
+
+    impl CryptoRng for OsRng {}
+}
@@ -1,7 +1,7 @@
 // Integration tests for ML-KEM-RS
 // These tests verify the overall correctness of the implementation
 
-use ml_kem_rs::{SELECTED_PARAMETER_SET, ML_KEM_512, ML_KEM_768, ML_KEM_1024};
+use ml_kem_rs::{params::ParameterSet, MlKem512, MlKem768, MlKem1024, N, Q, ML_KEM_512, ML_KEM_768, ML_KEM_1024};
 
 #[test]
 fn test_parameter_sets_defined() {
@@ -20,16 +20,37 @@ fn test_parameter_sets_defined() {
 }
 
 #[test]
-fn test_selected_parameter_set_is_768() {
-    assert_eq!(SELECTED_PARAMETER_SET.k, 3);
-    assert_eq!(SELECTED_PARAMETER_SET.eta1, 2);
+fn test_parameter_set_types_match_constants() {
+    // The marker types mirror the ParameterSetConstants values
+    assert_eq!((MlKem512::K, MlKem512::ETA1, MlKem512::ETA2, MlKem512::DU, MlKem512::DV), (2, 3, 2, 10, 4));
+    assert_eq!((MlKem768::K, MlKem768::ETA1, MlKem768::ETA2, MlKem768::DU, MlKem768::DV), (3, 2, 2, 10, 4));
+    assert_eq!((MlKem1024::K, MlKem1024::ETA1, MlKem1024::ETA2, MlKem1024::DU, MlKem1024::DV), (4, 2, 2, 11, 5));
+}
+
+#[test]
+fn test_parameter_set_sizes() {
+    // FIPS 203 Table 3: encapsulation key, decapsulation key, ciphertext and shared secret sizes
+    for (params, ek, dk, c) in [(ML_KEM_512, 800, 1632, 768), (ML_KEM_768, 1184, 2400, 1088), (ML_KEM_1024, 1568, 3168, 1568)] {
+        assert_eq!(params.encapsulation_key_len(), ek);
+        assert_eq!(params.decapsulation_key_len(), dk);
+        assert_eq!(params.ciphertext_len(), c);
+        assert_eq!(params.shared_secret_len(), 32);
+    }
+
+    // Usable in const contexts, e.g. to size buffers
+    const CIPHERTEXT_768: [u8; ML_KEM_768.ciphertext_len()] = [0; ML_KEM_768.ciphertext_len()];
+    assert_eq!(CIPHERTEXT_768.len(), 1088);
+
+    assert_eq!(MlKem512::CIPHERTEXT_LEN, ML_KEM_512.ciphertext_len());
+    assert_eq!(MlKem768::ENCAPSULATION_KEY_LEN, ML_KEM_768.encapsulation_key_len());
+    assert_eq!(MlKem1024::DECAPSULATION_KEY_LEN, ML_KEM_1024.decapsulation_key_len());
 }
 
 #[test]
 fn test_fips_203_constants() {
     // Verify FIPS 203 constants are correct
-    assert_eq!(SELECTED_PARAMETER_SET.n, 256, "n should be 256 for all ML-KEM variants");
-    assert_eq!(SELECTED_PARAMETER_SET.q, 3329, "q should be 3329 for all ML-KEM variants");
+    assert_eq!(N, 256, "n should be 256 for all ML-KEM variants");
+    assert_eq!(Q, 3329, "q should be 3329 for all ML-KEM variants");
 }
 
 #[test]
@@ -91,16 +112,39 @@ fn test_security_levels() {
 
 #[cfg(test)]
 mod full_workflow_tests {
-    use ml_kem_rs::{kpke::key_gen, cryptographic::*, algebraic::*};
+    use ml_kem_rs::{cryptographic::*, algebraic::*};
+    use ml_kem_rs::{kpke::{decrypt, encrypt, key_gen}, params::ParameterSet, MlKem512, MlKem768, MlKem1024};
     
     #[test]
+    #[cfg(feature = "getrandom")]
     fn test_complete_key_generation_workflow() {
         // Test the complete key generation workflow
         let mut d = [0u8; 32];
         getrandom::fill(&mut d).expect("Failed to generate random seed");
         
         // This should execute the complete key generation
-        let _ = key_gen(d);
+        key_gen::<MlKem768>(d);
+    }
+    
+    #[test]
+    fn test_complete_pke_workflow() {
+        // key_gen -> encrypt -> decrypt should recover the message, for a few fixed seeds
+        for seed in [0x00u8, 0x5a, 0xff] {
+            let d = [seed; 32];
+            let m = [seed ^ 0x0f; 32];
+            let r = [seed ^ 0xf0; 32];
+
+            pke_roundtrip::<MlKem512>(d, m, r);
+            pke_roundtrip::<MlKem768>(d, m, r);
+            pke_roundtrip::<MlKem1024>(d, m, r);
+        }
+    }
+
+    fn pke_roundtrip<P: ParameterSet>(d: [u8; 32], m: [u8; 32], r: [u8; 32]) {
+        let (ek, dk) = key_gen::<P>(d);
+        let c = encrypt::<P>(&ek, m, r);
+
+        assert_eq!(decrypt::<P>(&dk, &c), m);
     }
     
     #[test]
@@ -123,8 +167,8 @@ mod full_workflow_tests {
         use ml_kem_rs::polynomial::Poly16;
         
         let mut coeffs = [0u16; 256];
-        for i in 0..256 {
-            coeffs[i] = (i % 100) as u16;
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = (i % 100) as u16;
         }
         let poly = Poly16::new(&coeffs);
         
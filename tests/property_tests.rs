@@ -2,10 +2,10 @@
 // These tests verify mathematical properties and invariants
 
 use ml_kem_rs::{
-    polynomial::Poly16,
+    polynomial::{Poly16, PolyI16},
     algebraic::*,
     auxiliary::*,
-    SELECTED_PARAMETER_SET,
+    Q,
 };
 
 #[test]
@@ -13,15 +13,15 @@ fn property_modulo_reduces_to_range() {
     // Property: a % q should always be in [0, q)
     for i in 0..1000 {
         let mut coeffs = [0u16; 256];
-        for j in 0..256 {
-            coeffs[j] = ((i * j + 1234) % 65536) as u16;
+        for (j, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = ((i * j + 1234) % 65536) as u16;
         }
         let poly = Poly16::new(&coeffs);
-        let result = poly % (SELECTED_PARAMETER_SET.q as u16);
+        let result = PolyI16::from(poly).to_canonical();
         
         for k in 0..256 {
-            assert!(result[k] < SELECTED_PARAMETER_SET.q as u16,
-                    "Coefficient out of range: {} >= {}", result[k], SELECTED_PARAMETER_SET.q);
+            assert!(result[k] < Q as u16,
+                    "Coefficient out of range: {} >= {}", result[k], Q);
         }
     }
 }
@@ -31,8 +31,8 @@ fn property_ntt_preserves_coefficient_count() {
     // Property: NTT should not change the number of coefficients
     for seed in 0..10 {
         let mut coeffs = [0u16; 256];
-        for i in 0..256 {
-            coeffs[i] = ((seed * i + 42) % 3329) as u16;
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = ((seed * i + 42) % 3329) as u16;
         }
         let poly = Poly16::new(&coeffs);
         let transformed = ntt(poly);
@@ -60,14 +60,14 @@ fn property_sample_ntt_always_in_range() {
     for i in 0..5 {
         for j in 0..5 {
             let mut rho = [0u8; 32];
-            for k in 0..32 {
-                rho[k] = ((i * 31 + j * 17 + k) % 256) as u8;
+            for (k, byte) in rho.iter_mut().enumerate() {
+                *byte = ((i * 31 + j * 17 + k) % 256) as u8;
             }
             
             let sample = sample_ntt(rho, i as u8, j as u8);
             
             for k in 0..256 {
-                assert!(sample[k] < SELECTED_PARAMETER_SET.q as u16,
+                assert!(sample[k] < Q as u16,
                         "Sample out of range at ({},{},{}): {}", i, j, k, sample[k]);
             }
         }
@@ -111,16 +111,16 @@ fn property_polynomial_addition_associative() {
 #[test]
 fn property_polynomial_scalar_distributive() {
     // Property: k * (a + b) == k*a + k*b (when no overflow)
-    let poly_a = Poly16::new(&[10u16; 256]);
-    let poly_b = Poly16::new(&[20u16; 256]);
-    let k = 5u16;
+    let poly_a = PolyI16::new(&[10i16; 256]);
+    let poly_b = PolyI16::new(&[20i16; 256]);
+    let k = 5i16;
     
     let sum = poly_a + poly_b;
-    let scaled_sum = sum * k;
+    let scaled_sum = (sum * k).to_canonical();
     
     let scaled_a = poly_a * k;
     let scaled_b = poly_b * k;
-    let sum_scaled = scaled_a + scaled_b;
+    let sum_scaled = (scaled_a + scaled_b).to_canonical();
     
     for i in 0..256 {
         assert_eq!(scaled_sum[i], sum_scaled[i], "Scalar multiplication not distributive");
@@ -128,6 +128,7 @@ fn property_polynomial_scalar_distributive() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn property_bytes_to_bits_size() {
     // Property: bytes_to_bits on n bytes produces 8*n bits
     for n in 1..20 {
@@ -142,7 +143,7 @@ fn property_zeta_values_in_range() {
     // Property: All zeta values should be in [0, q)
     for i in 0..128 {
         let zeta = get_zeta_from_index(i);
-        assert!(zeta < SELECTED_PARAMETER_SET.q as u16,
+        assert!(zeta < Q as u16,
                 "Zeta[{}] = {} is out of range", i, zeta);
         assert!(zeta > 0 || i == 0, "Zeta[{}] = 0 (only allowed for index 0)", i);
     }
@@ -172,7 +173,7 @@ fn property_sample_poly_cbd_bounded() {
     for i in 0..256 {
         let c = sample[i];
         // After modular reduction, small values stay small, negative become large
-        assert!(c <= 2 || c >= SELECTED_PARAMETER_SET.q as u16 - 2,
+        assert!(c <= 2 || c >= Q as u16 - 2,
                 "CBD coefficient {} not bounded: {}", i, c);
     }
 }
@@ -182,14 +183,14 @@ fn property_ntt_output_bounded() {
     // Property: NTT output should be in [0, q)
     for seed in 0..10 {
         let mut coeffs = [0u16; 256];
-        for i in 0..256 {
-            coeffs[i] = ((seed * i + 7) % 3329) as u16;
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = ((seed * i + 7) % 3329) as u16;
         }
         let poly = Poly16::new(&coeffs);
         let transformed = ntt(poly);
         
         for i in 0..256 {
-            assert!(transformed[i] < SELECTED_PARAMETER_SET.q as u16,
+            assert!(transformed[i] < Q as u16,
                     "NTT output {} out of range: {}", i, transformed[i]);
         }
     }
@@ -200,8 +201,8 @@ fn stress_test_multiple_ntt_transforms() {
     // Stress test: Apply NTT multiple times with different inputs
     for seed in 0..20 {
         let mut coeffs = [0u16; 256];
-        for i in 0..256 {
-            coeffs[i] = ((seed * 13 + i * 7) % 3329) as u16;
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = ((seed * 13 + i * 7) % 3329) as u16;
         }
         let poly = Poly16::new(&coeffs);
         let _ = ntt(poly);
@@ -214,8 +215,8 @@ fn stress_test_sample_ntt_many_times() {
     // Stress test: Sample many times with different seeds
     for i in 0..10 {
         let mut rho = [0u8; 32];
-        for j in 0..32 {
-            rho[j] = ((i * j + 42) % 256) as u8;
+        for (j, byte) in rho.iter_mut().enumerate() {
+            *byte = ((i * j + 42) % 256) as u8;
         }
         
         let sample = sample_ntt(rho, 0, 0);
@@ -0,0 +1,126 @@
+// Runs the NIST ACVP-Server ML-KEM vectors in tests/vectors/ against the
+// FIPS 203 internal functions, for every parameter set
+
+use serde_json::Value;
+
+use ml_kem_rs::{hazmat::{encaps_internal, keygen_internal}, ml_kem::*, params::ParameterSet, MlKem512, MlKem768, MlKem1024};
+
+fn load(name: &str, file: &str) -> Value {
+    let path = format!("{}/tests/vectors/{}/{}", env!("CARGO_MANIFEST_DIR"), name, file);
+    let data = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {path}: {e}"));
+    serde_json::from_str(&data).unwrap_or_else(|e| panic!("Failed to parse {path}: {e}"))
+}
+
+fn hex(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("Expected a hex string");
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn hex32(value: &Value) -> [u8; 32] {
+    hex(value).try_into().expect("Expected 32 bytes")
+}
+
+// Pairs every prompt test case with its expected result by tgId and tcId
+fn cases<'a>(prompt: &'a Value, expected: &'a Value) -> Vec<(&'a Value, &'a Value, &'a Value)> {
+    let mut out = Vec::new();
+
+    for group in prompt["testGroups"].as_array().unwrap() {
+        let expected_group = expected["testGroups"].as_array().unwrap()
+            .iter()
+            .find(|g| g["tgId"] == group["tgId"])
+            .unwrap_or_else(|| panic!("No expected results for tgId {}", group["tgId"]));
+
+        for test in group["tests"].as_array().unwrap() {
+            let expected_test = expected_group["tests"].as_array().unwrap()
+                .iter()
+                .find(|t| t["tcId"] == test["tcId"])
+                .unwrap_or_else(|| panic!("No expected result for tcId {}", test["tcId"]));
+            out.push((group, test, expected_test));
+        }
+    }
+
+    out
+}
+
+fn check_keygen<P: ParameterSet>(test: &Value, expected: &Value) {
+    let (ek, dk) = keygen_internal::<P>(hex32(&test["d"]), hex32(&test["z"]));
+
+    assert_eq!(ek.as_bytes().as_ref(), hex(&expected["ek"]), "ek mismatch for tcId {}", test["tcId"]);
+    assert_eq!(dk.as_bytes().as_ref(), hex(&expected["dk"]), "dk mismatch for tcId {}", test["tcId"]);
+}
+
+fn check_encaps<P: ParameterSet>(test: &Value, expected: &Value) {
+    let ek = EncapsulationKey::<P>::try_from(&hex(&test["ek"])[..]).expect("Vector ek should be valid");
+    let (k, c) = encaps_internal(&ek, hex32(&test["m"]));
+
+    assert_eq!(c.as_bytes().as_ref(), hex(&expected["c"]), "c mismatch for tcId {}", test["tcId"]);
+    assert_eq!(k.as_bytes(), &hex32(&expected["k"]), "K mismatch for tcId {}", test["tcId"]);
+}
+
+fn check_decaps<P: ParameterSet>(group: &Value, test: &Value, expected: &Value) {
+    let dk = DecapsulationKey::<P>::try_from(&hex(&group["dk"])[..]).expect("Vector dk should be valid");
+    let c = Ciphertext::<P>::try_from(&hex(&test["c"])[..]).expect("Vector c should have the right length");
+
+    assert_eq!(decaps(&dk, &c).as_bytes(), &hex32(&expected["k"]), "K mismatch for tcId {} ({})", test["tcId"], expected["reason"]);
+}
+
+#[test]
+fn test_acvp_keygen() {
+    let prompt = load("ML-KEM-keyGen-FIPS203", "prompt.json");
+    let expected = load("ML-KEM-keyGen-FIPS203", "expectedResults.json");
+    let mut count = [0usize; 3];
+
+    for (group, test, expected_test) in cases(&prompt, &expected) {
+        match group["parameterSet"].as_str().unwrap() {
+            "ML-KEM-512" => { check_keygen::<MlKem512>(test, expected_test); count[0] += 1 }
+            "ML-KEM-768" => { check_keygen::<MlKem768>(test, expected_test); count[1] += 1 }
+            "ML-KEM-1024" => { check_keygen::<MlKem1024>(test, expected_test); count[2] += 1 }
+            other => panic!("Unknown parameter set {other}"),
+        }
+    }
+
+    // Guard against an empty or truncated vector file passing silently
+    assert_eq!(count, [25, 25, 25]);
+}
+
+#[test]
+fn test_acvp_encapsulation() {
+    let prompt = load("ML-KEM-encapDecap-FIPS203", "prompt.json");
+    let expected = load("ML-KEM-encapDecap-FIPS203", "expectedResults.json");
+    let mut count = [0usize; 3];
+
+    for (group, test, expected_test) in cases(&prompt, &expected) {
+        if group["function"] != "encapsulation" {
+            continue;
+        }
+        match group["parameterSet"].as_str().unwrap() {
+            "ML-KEM-512" => { check_encaps::<MlKem512>(test, expected_test); count[0] += 1 }
+            "ML-KEM-768" => { check_encaps::<MlKem768>(test, expected_test); count[1] += 1 }
+            "ML-KEM-1024" => { check_encaps::<MlKem1024>(test, expected_test); count[2] += 1 }
+            other => panic!("Unknown parameter set {other}"),
+        }
+    }
+
+    assert_eq!(count, [25, 25, 25]);
+}
+
+#[test]
+fn test_acvp_decapsulation() {
+    let prompt = load("ML-KEM-encapDecap-FIPS203", "prompt.json");
+    let expected = load("ML-KEM-encapDecap-FIPS203", "expectedResults.json");
+    let mut count = [0usize; 3];
+
+    for (group, test, expected_test) in cases(&prompt, &expected) {
+        if group["function"] != "decapsulation" {
+            continue;
+        }
+        match group["parameterSet"].as_str().unwrap() {
+            "ML-KEM-512" => { check_decaps::<MlKem512>(group, test, expected_test); count[0] += 1 }
+            "ML-KEM-768" => { check_decaps::<MlKem768>(group, test, expected_test); count[1] += 1 }
+            "ML-KEM-1024" => { check_decaps::<MlKem1024>(group, test, expected_test); count[2] += 1 }
+            other => panic!("Unknown parameter set {other}"),
+        }
+    }
+
+    assert_eq!(count, [10, 10, 10]);
+}
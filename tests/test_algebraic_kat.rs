@@ -0,0 +1,213 @@
+// Known-answer tests for the algebraic primitives, against the intermediate
+// values of K-PKE.KeyGen and K-PKE.Encrypt in tests/vectors/cctv/intermediate/
+// (C2SP CCTV, generated with the FIPS 203 reference implementation)
+
+use ml_kem_rs::{algebraic::*, auxiliary::byte_decode, cryptographic::prf, polynomial::{Poly16, PolyI16}, Q};
+
+struct Vectors {
+    name: &'static str,
+    k: usize,
+    eta1: usize,
+    eta2: usize,
+    values: Vec<(String, String)>,
+}
+
+impl Vectors {
+    fn load(name: &'static str, k: usize, eta1: usize, eta2: usize) -> Vectors {
+        let path = format!("{}/tests/vectors/cctv/intermediate/{}.txt", env!("CARGO_MANIFEST_DIR"), name);
+        let data = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {path}: {e}"));
+
+        // Lines are "name = value", where polynomials are written "name = {c0, c1, ...} = hex"
+        // and one name itself contains " = " ("dkPKE = NTT(s)")
+        let values = data.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (key, value) = line.split_once(" = ").unwrap_or_else(|| panic!("Malformed line in {path}: {line}"));
+                match value.split_once(" = ") {
+                    Some((name, value)) if !name.starts_with('{') => (format!("{key} = {name}"), value.to_string()),
+                    _ => (key.to_string(), value.to_string()),
+                }
+            })
+            .collect();
+
+        Vectors { name, k, eta1, eta2, values }
+    }
+
+    // "r" appears twice: first the 32-byte seed, then the sampled vector
+    fn nth(&self, key: &str, n: usize) -> &str {
+        self.values.iter()
+            .filter(|(k, _)| k == key)
+            .nth(n)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or_else(|| panic!("{}: no value #{n} for {key}", self.name))
+    }
+
+    fn get(&self, key: &str) -> &str {
+        self.nth(key, 0)
+    }
+
+    fn seed(&self, key: &str) -> [u8; 32] {
+        hex(self.get(key)).try_into().expect("Expected a 32-byte seed")
+    }
+
+    // ByteEncode12 of a vector of polynomials
+    fn polys(&self, key: &str, n: usize) -> Vec<Vec<u16>> {
+        let bytes = hex(self.nth(key, n));
+        assert_eq!(bytes.len() % 384, 0, "{}: {key} is not a multiple of 384 bytes", self.name);
+        bytes.chunks(384).map(|chunk| coeffs(byte_decode::<12, 384>(chunk.try_into().unwrap()))).collect()
+    }
+
+    // A single polynomial written out as {c0, c1, ...}
+    fn coefficients(&self, key: &str) -> Vec<u16> {
+        let value = self.get(key);
+        let list = value.strip_prefix('{').and_then(|v| v.split_once('}')).map(|(list, _)| list).unwrap_or_else(|| panic!("{key} is not a coefficient list"));
+        list.split(", ").map(|c| c.parse().unwrap()).collect()
+    }
+}
+
+fn all() -> [Vectors; 3] {
+    [
+        Vectors::load("ML-KEM-512", 2, 3, 2),
+        Vectors::load("ML-KEM-768", 3, 2, 2),
+        Vectors::load("ML-KEM-1024", 4, 2, 2),
+    ]
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn coeffs(f: Poly16) -> Vec<u16> {
+    f.into_iter().collect()
+}
+
+fn poly(c: &[u16]) -> Poly16 {
+    Poly16::new(c.try_into().unwrap())
+}
+
+fn cbd(eta: usize, seed: [u8; 32], b: u8) -> Poly16 {
+    match eta {
+        2 => sample_poly_cbd::<2>(prf::<2>(seed, b)),
+        3 => sample_poly_cbd::<3>(prf::<3>(seed, b)),
+        _ => unreachable!(),
+    }
+}
+
+// Canonical representatives of sums below 2^16
+fn reduce(f: Poly16) -> Poly16 {
+    PolyI16::from(f).to_canonical()
+}
+
+fn sub(f: Poly16, g: Poly16) -> Poly16 {
+    let mut out = [0u16; 256];
+    for i in 0..256 {
+        out[i] = ((f[i] as u32 + Q - g[i] as u32) % Q) as u16;
+    }
+    Poly16::new(&out)
+}
+
+#[test]
+fn test_sample_ntt_kat() {
+    for v in all() {
+        let rho = v.seed("ρ");
+
+        assert_eq!(coeffs(sample_ntt(rho, 0, 0)), v.coefficients("A[0, 0]"), "{}: A[0, 0]", v.name);
+
+        // Â[i, j] = SampleNTT(ρ || j || i), stored row by row
+        let a = v.polys("A", 0);
+        let a_t = v.polys("A^T", 0);
+        assert_eq!(a.len(), v.k * v.k);
+        for i in 0..v.k {
+            for j in 0..v.k {
+                let expected = coeffs(sample_ntt(rho, j as u8, i as u8));
+                assert_eq!(expected, a[i * v.k + j], "{}: A[{i}, {j}]", v.name);
+                assert_eq!(expected, a_t[j * v.k + i], "{}: A^T[{j}, {i}]", v.name);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sample_poly_cbd_kat() {
+    for v in all() {
+        let sigma = v.seed("σ");
+        let s = v.polys("s", 0);
+        let e = v.polys("e", 0);
+
+        assert_eq!(coeffs(cbd(v.eta1, sigma, 0)), v.coefficients("s[0]"), "{}: s[0]", v.name);
+        for i in 0..v.k {
+            assert_eq!(coeffs(cbd(v.eta1, sigma, i as u8)), s[i], "{}: s[{i}]", v.name);
+            assert_eq!(coeffs(cbd(v.eta1, sigma, (v.k + i) as u8)), e[i], "{}: e[{i}]", v.name);
+        }
+
+        // K-PKE.Encrypt samples y with η1, then e1 and e2 with η2
+        let r = v.seed("r");
+        let y = v.polys("r", 1);
+        let e1 = v.polys("e1", 0);
+        let e2 = v.polys("e2", 0);
+        for i in 0..v.k {
+            assert_eq!(coeffs(cbd(v.eta1, r, i as u8)), y[i], "{}: y[{i}]", v.name);
+            assert_eq!(coeffs(cbd(v.eta2, r, (v.k + i) as u8)), e1[i], "{}: e1[{i}]", v.name);
+        }
+        assert_eq!(coeffs(cbd(v.eta2, r, (2 * v.k) as u8)), e2[0], "{}: e2", v.name);
+    }
+}
+
+#[test]
+fn test_ntt_kat() {
+    for v in all() {
+        assert_eq!(coeffs(ntt(poly(&v.coefficients("s[0]")))), v.coefficients("NTT(s[0])"), "{}: NTT(s[0])", v.name);
+
+        for (input, output) in [("s", "dkPKE = NTT(s)"), ("e", "NTT(e)"), ("uᵈ", "NTT(uᵈ)")] {
+            for (i, (f, expected)) in v.polys(input, 0).iter().zip(v.polys(output, 0)).enumerate() {
+                assert_eq!(coeffs(ntt(poly(f))), expected, "{}: {output}[{i}]", v.name);
+            }
+        }
+        for (i, (f, expected)) in v.polys("r", 1).iter().zip(v.polys("NTT(r)", 0)).enumerate() {
+            assert_eq!(coeffs(ntt(poly(f))), expected, "{}: NTT(r)[{i}]", v.name);
+        }
+    }
+}
+
+#[test]
+fn test_ntt_inv_kat() {
+    for v in all() {
+        assert_eq!(coeffs(ntt_inv(poly(&v.coefficients("NTT(s[0])")))), v.coefficients("s[0]"), "{}: NTT⁻¹(NTT(s[0]))", v.name);
+
+        let a = v.polys("A", 0);
+        let y_hat = v.polys("NTT(r)", 0);
+        let e1 = v.polys("e1", 0);
+        let e2 = v.polys("e2", 0);
+        let s_hat = v.polys("dkPKE = NTT(s)", 0);
+        let t_hat = v.polys("t", 0);
+
+        // u = NTT⁻¹(Âᵀ ∘ ŷ) + e1
+        let u = v.polys("u", 0);
+        for i in 0..v.k {
+            let mut acc = Poly16::new(&[0u16; 256]);
+            for j in 0..v.k {
+                acc = reduce(acc + multiply_ntts(poly(&a[j * v.k + i]), poly(&y_hat[j])));
+            }
+            assert_eq!(coeffs(reduce(ntt_inv(acc) + poly(&e1[i]))), u[i], "{}: u[{i}]", v.name);
+        }
+        assert_eq!(u[0], v.coefficients("u[0]"), "{}: u[0]", v.name);
+
+        // v = NTT⁻¹(t̂ᵀ ∘ ŷ) + e2 + μ
+        let mu = v.polys("μ", 0);
+        let mut acc = Poly16::new(&[0u16; 256]);
+        for j in 0..v.k {
+            acc = reduce(acc + multiply_ntts(poly(&t_hat[j]), poly(&y_hat[j])));
+        }
+        let expected_v = reduce(ntt_inv(acc) + poly(&e2[0]) + poly(&mu[0]));
+        assert_eq!(coeffs(expected_v), v.coefficients("v"), "{}: v", v.name);
+
+        // w = v' - NTT⁻¹(ŝᵀ ∘ NTT(u'))
+        let u_hat = v.polys("NTT(uᵈ)", 0);
+        let mut acc = Poly16::new(&[0u16; 256]);
+        for j in 0..v.k {
+            acc = reduce(acc + multiply_ntts(poly(&s_hat[j]), poly(&u_hat[j])));
+        }
+        let w = sub(poly(&v.polys("vᵈ", 0)[0]), ntt_inv(acc));
+        assert_eq!(coeffs(w), v.polys("w", 0)[0], "{}: w", v.name);
+    }
+}
@@ -0,0 +1,191 @@
+use ml_kem_rs::{any::AnyCiphertext, ml_kem::KeyGenOptions, AnyMlKem, Error, ML_KEM_512, ML_KEM_768, ML_KEM_1024};
+
+#[test]
+fn test_from_name() {
+    assert_eq!(AnyMlKem::from_name("ML-KEM-512"), Some(AnyMlKem::MlKem512));
+    assert_eq!(AnyMlKem::from_name("ML-KEM-768"), Some(AnyMlKem::MlKem768));
+    assert_eq!(AnyMlKem::from_name("ML-KEM-1024"), Some(AnyMlKem::MlKem1024));
+    assert_eq!(AnyMlKem::from_name("ML-KEM-2048"), None);
+    assert_eq!(AnyMlKem::from_name("ml-kem-768"), None);
+}
+
+#[test]
+fn test_from_oid() {
+    assert_eq!(AnyMlKem::from_oid("2.16.840.1.101.3.4.4.1"), Some(AnyMlKem::MlKem512));
+    assert_eq!(AnyMlKem::from_oid("2.16.840.1.101.3.4.4.2"), Some(AnyMlKem::MlKem768));
+    assert_eq!(AnyMlKem::from_oid("2.16.840.1.101.3.4.4.3"), Some(AnyMlKem::MlKem1024));
+    assert_eq!(AnyMlKem::from_oid("2.16.840.1.101.3.4.4.4"), None);
+}
+
+#[test]
+fn test_from_tls_group() {
+    assert_eq!(AnyMlKem::from_tls_group(0x0200), Some(AnyMlKem::MlKem512));
+    assert_eq!(AnyMlKem::from_tls_group(0x0201), Some(AnyMlKem::MlKem768));
+    assert_eq!(AnyMlKem::from_tls_group(0x0202), Some(AnyMlKem::MlKem1024));
+    assert_eq!(AnyMlKem::from_tls_group(0x11EC), None);
+}
+
+#[test]
+fn test_identifiers_roundtrip() {
+    for p in AnyMlKem::ALL {
+        assert_eq!(AnyMlKem::from_name(p.name()), Some(p));
+        assert_eq!(AnyMlKem::from_oid(p.oid()), Some(p));
+        assert_eq!(AnyMlKem::from_tls_group(p.tls_group()), Some(p));
+    }
+}
+
+#[test]
+fn test_parameters_use_constants() {
+    assert_eq!(AnyMlKem::MlKem512.parameters().k, ML_KEM_512.k);
+    assert_eq!(AnyMlKem::MlKem768.parameters().k, ML_KEM_768.k);
+    assert_eq!(AnyMlKem::MlKem1024.parameters().k, ML_KEM_1024.k);
+    assert_eq!(AnyMlKem::MlKem512.parameters().eta1, 3);
+    assert_eq!(AnyMlKem::MlKem1024.parameters().du, 11);
+}
+
+#[test]
+fn test_roundtrip_all_parameter_sets() {
+    for p in AnyMlKem::ALL {
+        let (ek, dk) = p.keygen().unwrap();
+        assert_eq!(ek.parameter_set(), p);
+        assert_eq!(dk.parameter_set(), p);
+
+        let (shared_secret, c) = ek.encaps().unwrap();
+        assert_eq!(c.parameter_set(), p);
+        assert_eq!(dk.decaps(&c), Ok(shared_secret), "{} roundtrip failed", p.name());
+    }
+}
+
+#[test]
+fn test_sizes_follow_parameter_set() {
+    // FIPS 203 Table 3 (ek, dk, c)
+    let expected = [(800, 1632, 768), (1184, 2400, 1088), (1568, 3168, 1568)];
+
+    for (p, (ek_len, dk_len, c_len)) in AnyMlKem::ALL.into_iter().zip(expected) {
+        let (ek, dk) = p.keygen().unwrap();
+        let (_, c) = ek.encaps().unwrap();
+        assert_eq!(ek.as_bytes().len(), ek_len);
+        assert_eq!(dk.as_bytes().len(), dk_len);
+        assert_eq!(c.as_bytes().len(), c_len);
+    }
+}
+
+#[test]
+fn test_ciphertext_from_bytes() {
+    let p = AnyMlKem::from_tls_group(0x0201).unwrap();
+    let (ek, dk) = p.keygen().unwrap();
+    let (shared_secret, c) = ek.encaps().unwrap();
+
+    // Simulate receiving the ciphertext from the peer
+    let received = p.ciphertext_from_bytes(c.as_bytes()).unwrap();
+    assert_eq!(received, c);
+    assert_eq!(dk.decaps(&received), Ok(shared_secret));
+}
+
+#[test]
+fn test_ciphertext_from_bytes_wrong_length() {
+    assert_eq!(
+        AnyMlKem::MlKem512.ciphertext_from_bytes(&[0u8; 1088]),
+        Err(Error::InvalidLength { expected: 768, actual: 1088 })
+    );
+    assert_eq!(
+        AnyMlKem::MlKem768.ciphertext_from_bytes(&[0u8; 1087]),
+        Err(Error::InvalidLength { expected: 1088, actual: 1087 })
+    );
+    assert!(matches!(AnyMlKem::MlKem1024.ciphertext_from_bytes(&[0u8; 1568]), Ok(AnyCiphertext::MlKem1024(_))));
+}
+
+#[test]
+fn test_decaps_mismatched_parameter_set() {
+    let (_, dk) = AnyMlKem::MlKem768.keygen().unwrap();
+    let (ek, _) = AnyMlKem::MlKem1024.keygen().unwrap();
+    let (_, c) = ek.encaps().unwrap();
+
+    assert_eq!(dk.decaps(&c), Err(Error::ParameterSetMismatch));
+}
+
+#[test]
+fn test_encapsulation_key_from_bytes() {
+    let p = AnyMlKem::from_name("ML-KEM-512").unwrap();
+    let (ek, dk) = p.keygen().unwrap();
+
+    // Simulate receiving the encapsulation key from the peer
+    let received = p.encapsulation_key_from_bytes(ek.as_bytes()).unwrap();
+    assert_eq!(received, ek);
+
+    let (shared_secret, c) = received.encaps().unwrap();
+    assert_eq!(dk.decaps(&c), Ok(shared_secret));
+}
+
+#[test]
+fn test_encapsulation_key_from_bytes_rejects_invalid() {
+    let (ek, _) = AnyMlKem::MlKem1024.keygen().unwrap();
+
+    assert_eq!(
+        AnyMlKem::MlKem768.encapsulation_key_from_bytes(ek.as_bytes()),
+        Err(Error::InvalidLength { expected: 1184, actual: 1568 })
+    );
+
+    let mut bytes = ek.as_bytes().to_vec();
+    bytes[0] = 0xFF;
+    bytes[1] |= 0x0F;
+    assert_eq!(AnyMlKem::MlKem1024.encapsulation_key_from_bytes(&bytes), Err(Error::InvalidEncapsulationKey));
+}
+
+#[test]
+fn test_decapsulation_key_from_bytes() {
+    let p = AnyMlKem::from_oid("2.16.840.1.101.3.4.4.3").unwrap();
+    let (ek, dk) = p.keygen().unwrap();
+
+    // Simulate loading the decapsulation key from disk
+    let loaded = p.decapsulation_key_from_bytes(dk.as_bytes()).unwrap();
+    assert_eq!(loaded, dk);
+
+    let (shared_secret, c) = ek.encaps().unwrap();
+    assert_eq!(loaded.decaps(&c), Ok(shared_secret));
+}
+
+#[test]
+fn test_decapsulation_key_from_bytes_rejects_invalid() {
+    let (_, dk) = AnyMlKem::MlKem512.keygen().unwrap();
+
+    assert_eq!(
+        AnyMlKem::MlKem768.decapsulation_key_from_bytes(dk.as_bytes()),
+        Err(Error::InvalidLength { expected: 2400, actual: 1632 })
+    );
+
+    let mut bytes = dk.as_bytes().to_vec();
+    bytes[768 * 2 + 32] ^= 1;
+    assert_eq!(AnyMlKem::MlKem512.decapsulation_key_from_bytes(&bytes), Err(Error::InvalidDecapsulationKey));
+}
+
+#[test]
+fn test_keygen_with_pairwise_consistency_test() {
+    let options = KeyGenOptions { pairwise_consistency_test: true };
+
+    for p in AnyMlKem::ALL {
+        let (ek, dk) = p.keygen_with_options(options).unwrap();
+        assert_eq!(ek.parameter_set(), p);
+        assert_eq!(dk.parameter_set(), p);
+    }
+}
+
+#[test]
+fn test_decapsulation_key_from_seed() {
+    for p in AnyMlKem::ALL {
+        let (_, dk) = p.keygen().unwrap();
+        let seed = dk.to_seed().unwrap();
+
+        let restored = p.decapsulation_key_from_seed(seed);
+        assert_eq!(restored, dk);
+
+        let (shared_secret, c) = restored.encapsulation_key().encaps().unwrap();
+        assert_eq!(dk.decaps(&c), Ok(shared_secret));
+    }
+}
+
+#[test]
+fn test_any_decapsulation_key_debug_hides_key() {
+    let (_, dk) = AnyMlKem::MlKem1024.keygen().unwrap();
+    assert_eq!(format!("{dk:?}"), "AnyDecapsulationKey::MlKem1024 { .. }");
+}
@@ -40,12 +40,13 @@ fn test_bit_rev() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn test_bytes_to_bits() {
     // Test single byte
     let bytes = vec![0b10110100];
     let bits = bytes_to_bits(&bytes);
     assert_eq!(bits.len(), 8);
-    assert_eq!(bits, vec![0, 0, 1, 0, 1, 0, 1, 1]); // LSB first
+    assert_eq!(bits, vec![0, 0, 1, 0, 1, 1, 0, 1]); // LSB first
     
     // Test zero byte
     let bytes = vec![0];
@@ -66,9 +67,10 @@ fn test_bytes_to_bits() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn test_bits_to_bytes() {
     // Test single byte
-    let bits = vec![0, 0, 1, 0, 1, 0, 1, 1];
+    let bits = vec![0, 0, 1, 0, 1, 1, 0, 1];
     let bytes = bits_to_bytes(&bits);
     assert_eq!(bytes.len(), 1);
     assert_eq!(bytes[0], 0b10110100);
@@ -85,6 +87,7 @@ fn test_bits_to_bytes() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn test_bytes_bits_roundtrip() {
     // Test that bytes -> bits -> bytes preserves data
     let original_bytes = vec![42, 128, 255, 0, 127, 200];
@@ -120,6 +123,7 @@ fn test_zeta_values_consistency() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn test_bytes_to_bits_no_alternating_zeros() {
     // Regression test for the bug that caused alternating zeros
     let bytes = vec![255u8; 32]; // All bits should be 1
@@ -136,8 +140,184 @@ fn test_bytes_to_bits_no_alternating_zeros() {
     // Test pattern doesn't create alternating zeros
     let bytes = vec![0b10101010u8; 4];
     let bits = bytes_to_bits(&bytes);
-    let expected = vec![0, 1, 0, 1, 0, 1, 0, 1];
+    let expected = [0, 1, 0, 1, 0, 1, 0, 1];
     for i in 0..4 {
         assert_eq!(&bits[i*8..(i+1)*8], &expected[..], "Pattern mismatch at byte {}", i);
     }
 }
+
+fn encode_decode_roundtrip<const D: usize, const L: usize>() {
+    let mut coeffs = [0u16; 256];
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = ((i * 37 + 11) % (1 << D)) as u16;
+    }
+    let poly = ml_kem_rs::polynomial::Poly16::new(&coeffs);
+
+    let mut bytes = [0u8; L];
+    byte_encode::<D, L>(poly, &mut bytes);
+    let decoded = byte_decode::<D, L>(&bytes);
+
+    for i in 0..256 {
+        assert_eq!(decoded[i], coeffs[i] % 3329, "ByteEncode{} roundtrip failed at {}", D, i);
+    }
+}
+
+#[test]
+fn test_byte_encode_decode_roundtrip_all_d() {
+    encode_decode_roundtrip::<1, 32>();
+    encode_decode_roundtrip::<2, 64>();
+    encode_decode_roundtrip::<3, 96>();
+    encode_decode_roundtrip::<4, 128>();
+    encode_decode_roundtrip::<5, 160>();
+    encode_decode_roundtrip::<6, 192>();
+    encode_decode_roundtrip::<7, 224>();
+    encode_decode_roundtrip::<8, 256>();
+    encode_decode_roundtrip::<9, 288>();
+    encode_decode_roundtrip::<10, 320>();
+    encode_decode_roundtrip::<11, 352>();
+    encode_decode_roundtrip::<12, 384>();
+}
+
+#[test]
+fn test_byte_encode_12_known_answer() {
+    let mut coeffs = [0u16; 256];
+    coeffs[0] = 0x123;
+    coeffs[1] = 0x456;
+    let poly = ml_kem_rs::polynomial::Poly16::new(&coeffs);
+
+    let mut bytes = [0u8; 384];
+    byte_encode::<12, 384>(poly, &mut bytes);
+
+    // Coefficients are packed little-endian, 12 bits each
+    assert_eq!(&bytes[..3], &[0x23, 0x61, 0x45]);
+    assert!(bytes[3..].iter().all(|&b| b == 0));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_byte_encode_1_matches_bits() {
+    let mut coeffs = [0u16; 256];
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = (i % 3 == 0) as u16;
+    }
+    let poly = ml_kem_rs::polynomial::Poly16::new(&coeffs);
+
+    let mut bytes = [0u8; 32];
+    byte_encode::<1, 32>(poly, &mut bytes);
+
+    let bits = bytes_to_bits(&bytes);
+    for i in 0..256 {
+        assert_eq!(bits[i] as u16, coeffs[i]);
+    }
+}
+
+#[test]
+fn test_byte_decode_12_reduces_mod_q() {
+    // All-ones bytes decode to 4095 before reduction
+    let bytes = [0xFFu8; 384];
+    let decoded = byte_decode::<12, 384>(&bytes);
+
+    for i in 0..256 {
+        assert_eq!(decoded[i], 4095 - 3329);
+    }
+
+    // q itself decodes to 0
+    let mut bytes = [0u8; 384];
+    bytes[0] = (3329 & 0xFF) as u8;
+    bytes[1] = (3329 >> 8) as u8;
+    assert_eq!(byte_decode::<12, 384>(&bytes)[0], 0);
+}
+
+#[test]
+fn test_byte_decode_small_d_no_reduction() {
+    let bytes = [0xFFu8; 320];
+    let decoded = byte_decode::<10, 320>(&bytes);
+
+    for i in 0..256 {
+        assert_eq!(decoded[i], 1023);
+    }
+}
+
+fn compress_exhaustive<const D: usize>() {
+    for x in 0..3329u32 {
+        let mut coeffs = [0u16; 256];
+        coeffs[0] = x as u16;
+        let compressed = compress::<D>(ml_kem_rs::polynomial::Poly16::new(&coeffs));
+
+        // ⌈(2^d/q)·x⌋ mod 2^d computed with plain integer division
+        let expected = (((x << D) + 1664) / 3329) % (1 << D);
+        assert_eq!(compressed[0] as u32, expected, "Compress{} mismatch for x = {}", D, x);
+
+        // Decompress(Compress(x)) stays within ⌈q/2^(d+1)⌋ of x
+        let decompressed = decompress::<D>(compressed)[0] as i32;
+        let diff = (decompressed - x as i32).rem_euclid(3329);
+        let error = diff.min(3329 - diff);
+        let bound = (3329 + (1 << D)) / (1 << (D + 1));
+        assert!(error <= bound, "Compress{} error {} exceeds {} for x = {}", D, error, bound, x);
+    }
+}
+
+fn decompress_compress_identity<const D: usize>() {
+    for y in 0..(1u16 << D) {
+        let mut coeffs = [0u16; 256];
+        coeffs[0] = y;
+        let poly = ml_kem_rs::polynomial::Poly16::new(&coeffs);
+
+        assert_eq!(compress::<D>(decompress::<D>(poly))[0], y, "Compress{}(Decompress{}) failed for y = {}", D, D, y);
+    }
+}
+
+#[test]
+fn test_compress_exhaustive_d1() {
+    compress_exhaustive::<1>();
+    decompress_compress_identity::<1>();
+}
+
+#[test]
+fn test_compress_exhaustive_dv() {
+    compress_exhaustive::<4>();
+    compress_exhaustive::<5>();
+    decompress_compress_identity::<4>();
+    decompress_compress_identity::<5>();
+}
+
+#[test]
+fn test_compress_exhaustive_du() {
+    compress_exhaustive::<10>();
+    compress_exhaustive::<11>();
+    decompress_compress_identity::<10>();
+    decompress_compress_identity::<11>();
+}
+
+#[test]
+fn test_compress_1_rounds_to_nearest() {
+    let mut coeffs = [0u16; 256];
+    coeffs[0] = 832;  // just below q/4, rounds to 0
+    coeffs[1] = 833;  // rounds to 1
+    coeffs[2] = 2496; // just below 3q/4, rounds to 1
+    coeffs[3] = 2497; // rounds back to 0
+    let compressed = compress::<1>(ml_kem_rs::polynomial::Poly16::new(&coeffs));
+
+    assert_eq!(compressed[0], 0);
+    assert_eq!(compressed[1], 1);
+    assert_eq!(compressed[2], 1);
+    assert_eq!(compressed[3], 0);
+}
+
+#[test]
+fn test_get_gamma_from_index() {
+    // γ_i = ζ^(2·BitRev7(i)+1) with ζ = 17
+    for i in 0..128 {
+        let mut expected = 1u32;
+        for _ in 0..(2 * bit_rev(i as u8) as u32 + 1) {
+            expected = expected * 17 % 3329;
+        }
+        assert_eq!(get_gamma_from_index(i) as u32, expected, "Gamma mismatch at index {}", i);
+    }
+
+    // FIPS 203 Appendix A: the first gammas are 17, -17, 2761, -2761
+    assert_eq!(get_gamma_from_index(0), 17);
+    assert_eq!(get_gamma_from_index(1), 3329 - 17);
+    assert_eq!(get_gamma_from_index(2), 2761);
+    assert_eq!(get_gamma_from_index(3), 3329 - 2761);
+}
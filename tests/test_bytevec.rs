@@ -64,11 +64,11 @@ fn test_bytevec_different_values() {
     let mut arr128 = [0u8; 128];
     let mut arr192 = [0u8; 192];
     
-    for i in 0..128 {
-        arr128[i] = i as u8;
+    for (i, byte) in arr128.iter_mut().enumerate() {
+        *byte = i as u8;
     }
-    for i in 0..192 {
-        arr192[i] = i as u8;
+    for (i, byte) in arr192.iter_mut().enumerate() {
+        *byte = i as u8;
     }
     
     let vec128 = ByteVec::Vec128(arr128);
@@ -76,8 +76,8 @@ fn test_bytevec_different_values() {
     
     match vec128 {
         ByteVec::Vec128(data) => {
-            for i in 0..128 {
-                assert_eq!(data[i], i as u8);
+            for (i, byte) in data.iter().enumerate() {
+                assert_eq!(*byte, i as u8);
             }
         }
         _ => panic!("Wrong variant"),
@@ -85,8 +85,8 @@ fn test_bytevec_different_values() {
     
     match vec192 {
         ByteVec::Vec192(data) => {
-            for i in 0..192 {
-                assert_eq!(data[i], i as u8);
+            for (i, byte) in data.iter().enumerate() {
+                assert_eq!(*byte, i as u8);
             }
         }
         _ => panic!("Wrong variant"),
@@ -111,6 +111,18 @@ fn test_j_different_inputs() {
     assert_ne!(result1, result2, "Different inputs should produce different outputs");
 }
 
+#[test]
+fn test_j_concat_matches_j() {
+    // J(z || c) as used by implicit rejection, without building z || c
+    let z = [0x5au8; 32];
+    let c = [0xa5u8; 1088];
+    let mut concatenated = z.to_vec();
+    concatenated.extend_from_slice(&c);
+
+    assert_eq!(j_concat(&z, &c), j(&concatenated));
+    assert_eq!(j_concat(&[], &c), j(&c));
+}
+
 #[test]
 fn test_g_output_sizes() {
     let input = b"test";
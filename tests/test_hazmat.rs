@@ -0,0 +1,91 @@
+use ml_kem_rs::{cryptographic::h, hazmat::*, ml_kem::*, params::ParameterSet, MlKem512, MlKem768, MlKem1024};
+
+fn hex<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    for (i, coeff) in out.iter_mut().enumerate().take(L) {
+        *coeff = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    out
+}
+
+fn check_keygen_kat<P: ParameterSet>(d: &str, z: &str, h_ek: &str, h_dk: &str) {
+    let (ek, dk) = keygen_internal::<P>(hex(d), hex(z));
+
+    assert_eq!(h(ek.as_bytes().as_ref()), hex::<32>(h_ek));
+    assert_eq!(h(dk.as_bytes().as_ref()), hex::<32>(h_dk));
+}
+
+#[test]
+fn test_keygen_internal_nist_vectors() {
+    // NIST ACVP ML-KEM-keyGen-FIPS203 tcId 1, 26 and 51, compared through H(ek) and H(dk)
+    check_keygen_kat::<MlKem512>(
+        "2CB843A02EF02EE109305F39119FABF49AB90A57FFECB3A0E75E179450F52761",
+        "84CC9121AE56FBF39E67ADBD83AD2D3E3BB80843645206BDD9F2F629E3CC49B7",
+        "620130d6c2b8c904a3bb9307be5103f8d814505fb6a60af7937ea6caa117315e",
+        "0ca760171ce5fa53ca02c13906a011fa994924f9eaa46c5930c80356bc73850e",
+    );
+    check_keygen_kat::<MlKem768>(
+        "E34A701C4C87582F42264EE422D3C684D97611F2523EFE0C998AF05056D693DC",
+        "A85768F3486BD32A01BF9A8F21EA938E648EAE4E5448C34C3EB88820B159EEDD",
+        "e29020839d052fa372585627f8b59ee312ae414c979d825f06a6929a79625718",
+        "a4e8ba80bb7a745e936d47784c07ffa6a314caf5a8deb4648c5c2d6ae930ebcd",
+    );
+    check_keygen_kat::<MlKem1024>(
+        "49AC8B99BB1E6A8EA818261F8BE68BDEAA52897E7EC6C40B530BC760AB77DCE3",
+        "99E3246884181F8E1DD44E0C7629093330221FD67D9B7D6E1510B2DBAD8762F7",
+        "d2e574dfd8cd0ae893aa7e125b44b924f45223ec09f2ad1141ea93a68050dbf6",
+        "214139261817ab6ff82f22b0af1a66cf0a419f45bb932d430072d6d47f9ff4de",
+    );
+}
+
+#[test]
+fn test_keygen_internal_deterministic() {
+    let (ek1, dk1) = keygen_internal::<MlKem768>([1u8; 32], [2u8; 32]);
+    let (ek2, dk2) = keygen_internal::<MlKem768>([1u8; 32], [2u8; 32]);
+
+    assert_eq!(ek1, ek2);
+    assert_eq!(dk1, dk2);
+}
+
+#[test]
+fn test_keygen_internal_matches_from_seed() {
+    let (d, z) = ([3u8; 32], [4u8; 32]);
+    let (_, dk) = keygen_internal::<MlKem512>(d, z);
+
+    let mut seed = [0u8; 64];
+    seed[..32].copy_from_slice(&d);
+    seed[32..].copy_from_slice(&z);
+    assert_eq!(DecapsulationKey::<MlKem512>::from_seed(seed), dk);
+    assert_eq!(dk.to_seed(), Some(seed));
+}
+
+#[test]
+fn test_encaps_internal_deterministic() {
+    let (ek, dk) = keygen_internal::<MlKem1024>([5u8; 32], [6u8; 32]);
+
+    let (k1, c1) = encaps_internal(&ek, [7u8; 32]);
+    let (k2, c2) = encaps_internal(&ek, [7u8; 32]);
+    assert_eq!(k1, k2);
+    assert_eq!(c1, c2);
+
+    let (k3, c3) = encaps_internal(&ek, [8u8; 32]);
+    assert_ne!(k1, k3);
+    assert_ne!(c1, c3);
+
+    assert_eq!(decaps(&dk, &c1), k1);
+}
+
+#[test]
+fn test_encaps_internal_shared_secret_derivation() {
+    // (K, r) = G(m || H(ek)), so K does not depend on the encryption itself
+    let (ek, _) = keygen_internal::<MlKem768>([9u8; 32], [10u8; 32]);
+    let m = [11u8; 32];
+
+    let mut seed = [0u8; 64];
+    seed[..32].copy_from_slice(&m);
+    seed[32..].copy_from_slice(&h(ek.as_bytes()));
+    let (expected, _) = ml_kem_rs::cryptographic::g(&seed);
+
+    let (shared_secret, _) = encaps_internal(&ek, m);
+    assert_eq!(shared_secret.as_bytes(), &expected);
+}
@@ -1,21 +1,21 @@
-use ml_kem_rs::{kpke::key_gen, SELECTED_PARAMETER_SET};
+use ml_kem_rs::{kpke::{decrypt, encrypt, key_gen}, params::ParameterSet, MlKem512, MlKem768, MlKem1024, ML_KEM_768};
 
 #[test]
 fn test_key_gen_completes() {
     let d = [42u8; 32];
     // Should not panic
-    let _ = key_gen(d);
+    key_gen::<MlKem768>(d);
 }
 
 #[test]
 fn test_key_gen_deterministic() {
     let d = [123u8; 32];
-    let result1 = key_gen(d);
-    let result2 = key_gen(d);
+    let (ek1, dk1) = key_gen::<MlKem768>(d);
+    let (ek2, dk2) = key_gen::<MlKem768>(d);
     
     // Key generation with same seed should produce same result
-    // This tests that the process is deterministic
-    // (Assuming key_gen returns something comparable)
+    assert_eq!(ek1, ek2, "Encryption keys differ for the same seed");
+    assert_eq!(dk1, dk2, "Decryption keys differ for the same seed");
 }
 
 #[test]
@@ -23,25 +23,65 @@ fn test_key_gen_different_seeds() {
     let d1 = [1u8; 32];
     let d2 = [2u8; 32];
     
-    let _result1 = key_gen(d1);
-    let _result2 = key_gen(d2);
+    let (ek1, dk1) = key_gen::<MlKem768>(d1);
+    let (ek2, dk2) = key_gen::<MlKem768>(d2);
     
     // Different seeds should produce different keys
-    // (Once key_gen returns keys, compare them)
+    assert_ne!(ek1, ek2, "Different seeds should produce different encryption keys");
+    assert_ne!(dk1, dk2, "Different seeds should produce different decryption keys");
+}
+
+#[test]
+fn test_key_gen_key_sizes() {
+    let (ek, dk) = key_gen::<MlKem768>([7u8; 32]);
+
+    // ekPKE = ByteEncode12(t̂) || ρ, dkPKE = ByteEncode12(ŝ)
+    assert_eq!(ek.len(), 384 * ML_KEM_768.k as usize + 32);
+    assert_eq!(dk.len(), 384 * ML_KEM_768.k as usize);
+    assert_eq!(ek.len(), 1184);
+    assert_eq!(dk.len(), 1152);
+}
+
+#[test]
+fn test_key_gen_ek_ends_with_rho() {
+    let d = [99u8; 32];
+    let (ek, _) = key_gen::<MlKem768>(d);
+
+    // ρ is the first half of G(d || k)
+    let mut seed = [0u8; 33];
+    seed[..32].copy_from_slice(&d);
+    seed[32] = ML_KEM_768.k as u8;
+    let (rho, _) = ml_kem_rs::cryptographic::g(&seed);
+
+    assert_eq!(&ek[1184 - 32..], &rho);
+}
+
+#[test]
+fn test_key_gen_coefficients_in_range() {
+    let (ek, dk) = key_gen::<MlKem768>([3u8; 32]);
+
+    // Every 12-bit coefficient of t̂ and ŝ must be reduced mod q
+    for bytes in [&ek[..1152], &dk[..]] {
+        for chunk in bytes.chunks(3) {
+            let c0 = chunk[0] as u16 | ((chunk[1] as u16 & 0x0F) << 8);
+            let c1 = (chunk[1] as u16 >> 4) | ((chunk[2] as u16) << 4);
+            assert!(c0 < 3329 && c1 < 3329, "Encoded coefficient out of range");
+        }
+    }
 }
 
 #[test]
 fn test_key_gen_zero_seed() {
     let d = [0u8; 32];
     // Should handle zero seed gracefully
-    let _ = key_gen(d);
+    key_gen::<MlKem768>(d);
 }
 
 #[test]
 fn test_key_gen_max_seed() {
     let d = [255u8; 32];
     // Should handle max value seed gracefully
-    let _ = key_gen(d);
+    key_gen::<MlKem768>(d);
 }
 
 #[test]
@@ -49,23 +89,126 @@ fn test_key_gen_runs_without_panic() {
     // Test with various random-looking seeds
     for i in 0..10 {
         let mut d = [0u8; 32];
-        for j in 0..32 {
-            d[j] = ((i * 13 + j * 7) % 256) as u8;
+        for (j, byte) in d.iter_mut().enumerate() {
+            *byte = ((i * 13 + j * 7) % 256) as u8;
+        }
+        key_gen::<MlKem768>(d);
+    }
+}
+
+fn sizes<P: ParameterSet>() -> (usize, usize, usize) {
+    let (ek, dk) = key_gen::<P>([21u8; 32]);
+    let c = encrypt::<P>(&ek, [22u8; 32], [23u8; 32]);
+    (ek.as_ref().len(), dk.as_ref().len(), c.as_ref().len())
+}
+
+fn roundtrip<P: ParameterSet>() {
+    let (ek, dk) = key_gen::<P>([24u8; 32]);
+    let m = [0x5Au8; 32];
+    let c = encrypt::<P>(&ek, m, [25u8; 32]);
+    assert_eq!(decrypt::<P>(&dk, &c), m);
+}
+
+#[test]
+fn test_all_parameter_sets_sizes() {
+    // FIPS 203 Table 3 (ekPKE, dkPKE, c)
+    assert_eq!(sizes::<MlKem512>(), (800, 768, 768));
+    assert_eq!(sizes::<MlKem768>(), (1184, 1152, 1088));
+    assert_eq!(sizes::<MlKem1024>(), (1568, 1536, 1568));
+}
+
+#[test]
+fn test_all_parameter_sets_roundtrip() {
+    roundtrip::<MlKem512>();
+    roundtrip::<MlKem768>();
+    roundtrip::<MlKem1024>();
+}
+
+#[test]
+fn test_parameter_sets_give_different_keys() {
+    // k is part of the G(d || k) seed
+    let (ek512, _) = key_gen::<MlKem512>([26u8; 32]);
+    let (ek768, _) = key_gen::<MlKem768>([26u8; 32]);
+    assert_ne!(ek512[800 - 32..], ek768[1184 - 32..]);
+}
+
+#[test]
+fn test_encrypt_ciphertext_size() {
+    let (ek, _) = key_gen::<MlKem768>([5u8; 32]);
+    let c = encrypt::<MlKem768>(&ek, [1u8; 32], [2u8; 32]);
+
+    // c = c1 || c2 with 32·du·k + 32·dv bytes
+    let du = ML_KEM_768.du as usize;
+    let dv = ML_KEM_768.dv as usize;
+    assert_eq!(c.len(), 32 * (du * ML_KEM_768.k as usize + dv));
+    assert_eq!(c.len(), 1088);
+}
+
+#[test]
+fn test_encrypt_deterministic() {
+    let (ek, _) = key_gen::<MlKem768>([8u8; 32]);
+    let c1 = encrypt::<MlKem768>(&ek, [3u8; 32], [4u8; 32]);
+    let c2 = encrypt::<MlKem768>(&ek, [3u8; 32], [4u8; 32]);
+
+    assert_eq!(c1, c2, "Encryption with the same randomness should be deterministic");
+}
+
+#[test]
+fn test_encrypt_different_randomness() {
+    let (ek, _) = key_gen::<MlKem768>([8u8; 32]);
+    let c1 = encrypt::<MlKem768>(&ek, [3u8; 32], [4u8; 32]);
+    let c2 = encrypt::<MlKem768>(&ek, [3u8; 32], [5u8; 32]);
+
+    assert_ne!(c1, c2, "Different randomness should produce different ciphertexts");
+}
+
+#[test]
+fn test_encrypt_different_messages() {
+    let (ek, _) = key_gen::<MlKem768>([8u8; 32]);
+    let c1 = encrypt::<MlKem768>(&ek, [0u8; 32], [4u8; 32]);
+    let c2 = encrypt::<MlKem768>(&ek, [255u8; 32], [4u8; 32]);
+
+    // Same r gives the same c1, only c2 carries the message
+    let c1_len = 32 * ML_KEM_768.du as usize * ML_KEM_768.k as usize;
+    assert_eq!(c1[..c1_len], c2[..c1_len]);
+    assert_ne!(c1[c1_len..], c2[c1_len..]);
+}
+
+#[test]
+fn test_decrypt_roundtrip() {
+    let (ek, dk) = key_gen::<MlKem768>([11u8; 32]);
+
+    for seed in 0..10u8 {
+        let mut m = [0u8; 32];
+        for (i, byte) in m.iter_mut().enumerate() {
+            *byte = seed.wrapping_mul(31).wrapping_add(i as u8 * 7);
         }
-        let _ = key_gen(d);
+        let c = encrypt::<MlKem768>(&ek, m, [seed; 32]);
+        assert_eq!(decrypt::<MlKem768>(&dk, &c), m, "Decryption failed for seed {}", seed);
     }
 }
 
 #[test]
-fn test_key_gen_parameter_set_ml_kem_768() {
-    // Verify the selected parameter set is ML-KEM-768
-    assert_eq!(SELECTED_PARAMETER_SET.n, 256);
-    assert_eq!(SELECTED_PARAMETER_SET.k, 3);
-    assert_eq!(SELECTED_PARAMETER_SET.q, 3329);
-    assert_eq!(SELECTED_PARAMETER_SET.eta1, 2);
+fn test_decrypt_edge_messages() {
+    let (ek, dk) = key_gen::<MlKem768>([12u8; 32]);
+
+    for m in [[0u8; 32], [255u8; 32]] {
+        let c = encrypt::<MlKem768>(&ek, m, [13u8; 32]);
+        assert_eq!(decrypt::<MlKem768>(&dk, &c), m);
+    }
+}
+
+#[test]
+fn test_decrypt_wrong_key() {
+    let (ek, _) = key_gen::<MlKem768>([14u8; 32]);
+    let (_, other_dk) = key_gen::<MlKem768>([15u8; 32]);
+
+    let m = [0xA5u8; 32];
+    let c = encrypt::<MlKem768>(&ek, m, [16u8; 32]);
+    assert_ne!(decrypt::<MlKem768>(&other_dk, &c), m, "Decryption with the wrong key should not recover m");
 }
 
-#[cfg(test)]
+#[cfg(feature = "getrandom")]
 mod key_gen_integration_tests {
     use super::*;
     
@@ -75,7 +218,7 @@ mod key_gen_integration_tests {
         let mut d = [0u8; 32];
         getrandom::fill(&mut d).expect("Failed to generate random bytes");
         
-        let _ = key_gen(d);
+        key_gen::<MlKem768>(d);
     }
     
     #[test]
@@ -84,7 +227,7 @@ mod key_gen_integration_tests {
         for _ in 0..5 {
             let mut d = [0u8; 32];
             getrandom::fill(&mut d).expect("Failed to generate random bytes");
-            let _ = key_gen(d);
+            key_gen::<MlKem768>(d);
         }
     }
 }
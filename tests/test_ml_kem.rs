@@ -0,0 +1,510 @@
+use ml_kem_rs::{cryptographic::h, ml_kem::*, params::ParameterSet, Error, MlKem512, MlKem768, MlKem1024};
+
+#[test]
+fn test_key_sizes() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+
+    // FIPS 203 Table 3: ek is 384k + 32 bytes, dk is 768k + 96 bytes
+    let k = MlKem768::K;
+    assert_eq!(ek.as_bytes().len(), 384 * k + 32);
+    assert_eq!(dk.as_bytes().len(), 768 * k + 96);
+    assert_eq!(ek.as_bytes().len(), 1184);
+    assert_eq!(dk.as_bytes().len(), 2400);
+}
+
+#[test]
+fn test_ciphertext_size() {
+    let (ek, _) = keygen::<MlKem768>().unwrap();
+    let (_, c) = encaps(&ek).unwrap();
+
+    assert_eq!(c.as_bytes().len(), 1088);
+}
+
+#[test]
+fn test_encaps_decaps_roundtrip() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+
+    assert_eq!(decaps(&dk, &c), shared_secret, "Shared secrets should match");
+}
+
+#[test]
+fn test_encaps_decaps_multiple_rounds() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+
+    for _ in 0..10 {
+        let (shared_secret, c) = encaps(&ek).unwrap();
+        assert_eq!(decaps(&dk, &c), shared_secret);
+    }
+}
+
+#[test]
+fn test_keygen_produces_different_keys() {
+    let (ek1, dk1) = keygen::<MlKem768>().unwrap();
+    let (ek2, dk2) = keygen::<MlKem768>().unwrap();
+
+    assert_ne!(ek1, ek2, "Two key generations should not produce the same ek");
+    assert_ne!(dk1, dk2, "Two key generations should not produce the same dk");
+}
+
+#[test]
+fn test_encaps_produces_different_secrets() {
+    let (ek, _) = keygen::<MlKem768>().unwrap();
+    let (ss1, c1) = encaps(&ek).unwrap();
+    let (ss2, c2) = encaps(&ek).unwrap();
+
+    assert_ne!(ss1, ss2, "Two encapsulations should not produce the same secret");
+    assert_ne!(c1, c2, "Two encapsulations should not produce the same ciphertext");
+}
+
+#[test]
+fn test_dk_embeds_ek() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let k = MlKem768::K;
+
+    // dk = dkPKE || ek || H(ek) || z
+    assert_eq!(&dk.as_bytes()[384 * k..768 * k + 32], &ek.as_bytes()[..]);
+    assert_eq!(&dk.as_bytes()[768 * k + 32..768 * k + 64], &ml_kem_rs::cryptographic::h(ek.as_bytes()));
+}
+
+#[test]
+fn test_ciphertext_from_bytes() {
+    let (ek, _) = keygen::<MlKem768>().unwrap();
+    let (_, c) = encaps(&ek).unwrap();
+
+    let copy = Ciphertext::new(*c.as_bytes());
+    assert_eq!(copy, c);
+}
+
+#[test]
+fn test_decaps_modified_ciphertext_implicit_rejection() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+
+    let mut bytes = *c.as_bytes();
+    bytes[0] ^= 1;
+    let modified = Ciphertext::new(bytes);
+
+    // Decaps must not fail, it returns K̄ = J(z || c) instead
+    let rejected = decaps(&dk, &modified);
+    assert_ne!(rejected, shared_secret, "Modified ciphertext should not yield the real secret");
+
+    let mut rejection_input = dk.as_bytes()[2400 - 32..].to_vec();
+    rejection_input.extend_from_slice(modified.as_bytes());
+    assert_eq!(rejected.as_bytes(), &ml_kem_rs::cryptographic::j(&rejection_input));
+}
+
+#[test]
+fn test_decaps_implicit_rejection_deterministic() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let (_, c) = encaps(&ek).unwrap();
+
+    let mut bytes = *c.as_bytes();
+    bytes[1088 - 1] ^= 0x80;
+    let modified = Ciphertext::new(bytes);
+
+    assert_eq!(decaps(&dk, &modified), decaps(&dk, &modified));
+}
+
+#[test]
+fn test_decaps_wrong_key_implicit_rejection() {
+    let (ek, _) = keygen::<MlKem768>().unwrap();
+    let (_, other_dk) = keygen::<MlKem768>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+
+    assert_ne!(decaps(&other_dk, &c), shared_secret);
+}
+
+fn roundtrip<P: ParameterSet>() -> (usize, usize, usize) {
+    let (ek, dk) = keygen::<P>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+    assert_eq!(decaps(&dk, &c), shared_secret);
+
+    (ek.as_bytes().as_ref().len(), dk.as_bytes().as_ref().len(), c.as_bytes().as_ref().len())
+}
+
+#[test]
+fn test_all_parameter_sets_in_one_build() {
+    // FIPS 203 Table 3 (ek, dk, c)
+    assert_eq!(roundtrip::<MlKem512>(), (800, 1632, 768));
+    assert_eq!(roundtrip::<MlKem768>(), (1184, 2400, 1088));
+    assert_eq!(roundtrip::<MlKem1024>(), (1568, 3168, 1568));
+}
+
+#[test]
+fn test_all_parameter_sets_implicit_rejection() {
+    fn check<P: ParameterSet>() {
+        let (ek, dk) = keygen::<P>().unwrap();
+        let (shared_secret, c) = encaps(&ek).unwrap();
+
+        let mut bytes = *c.as_bytes();
+        bytes.as_mut()[0] ^= 1;
+        assert_ne!(decaps(&dk, &Ciphertext::new(bytes)), shared_secret);
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_encapsulation_key_try_from_valid() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+
+    let parsed = EncapsulationKey::<MlKem768>::try_from(&ek.as_bytes()[..]).unwrap();
+    assert_eq!(parsed, ek);
+
+    let (shared_secret, c) = encaps(&parsed).unwrap();
+    assert_eq!(decaps(&dk, &c), shared_secret);
+}
+
+#[test]
+fn test_encapsulation_key_try_from_wrong_length() {
+    let (ek, _) = keygen::<MlKem768>().unwrap();
+
+    assert_eq!(
+        EncapsulationKey::<MlKem768>::try_from(&ek.as_bytes()[..1183]),
+        Err(Error::InvalidLength { expected: 1184, actual: 1183 })
+    );
+    assert_eq!(
+        EncapsulationKey::<MlKem512>::try_from(&ek.as_bytes()[..]),
+        Err(Error::InvalidLength { expected: 800, actual: 1184 })
+    );
+}
+
+#[test]
+fn test_encapsulation_key_modulus_check() {
+    fn check<P: ParameterSet>() {
+        let (ek, _) = keygen::<P>().unwrap();
+        let bytes = ek.as_bytes().as_ref();
+        let t_len = bytes.len() - 32;
+
+        // Every 12-bit coefficient position of t̂, set to q and to 2^12 - 1
+        for i in (0..t_len / 3).step_by(17) {
+            for value in [3329u16, 4095] {
+                let mut modified = bytes.to_vec();
+                if i % 2 == 0 {
+                    modified[3 * i] = value as u8;
+                    modified[3 * i + 1] = (modified[3 * i + 1] & 0xF0) | (value >> 8) as u8;
+                } else {
+                    modified[3 * i + 1] = (modified[3 * i + 1] & 0x0F) | ((value as u8 & 0x0F) << 4);
+                    modified[3 * i + 2] = (value >> 4) as u8;
+                }
+                assert_eq!(EncapsulationKey::<P>::try_from(&modified[..]), Err(Error::InvalidEncapsulationKey));
+            }
+        }
+
+        // ρ is not checked, any 32 bytes are accepted
+        let mut modified = bytes.to_vec();
+        modified[t_len..].fill(0xFF);
+        assert!(EncapsulationKey::<P>::try_from(&modified[..]).is_ok());
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_encapsulation_key_max_reduced_coefficient() {
+    // q - 1 = 3328 is the largest valid coefficient
+    let mut bytes = [0u8; 1184];
+    bytes[0] = 0x00;
+    bytes[1] = 0x0D;
+    assert!(EncapsulationKey::<MlKem768>::try_from(&bytes[..]).is_ok());
+
+    bytes[0] = 0x01;
+    assert_eq!(EncapsulationKey::<MlKem768>::try_from(&bytes[..]), Err(Error::InvalidEncapsulationKey));
+}
+
+#[test]
+fn test_decapsulation_key_try_from_valid() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+
+    let loaded = DecapsulationKey::<MlKem768>::try_from(&dk.as_bytes()[..]).unwrap();
+    assert_eq!(loaded, dk);
+
+    let (shared_secret, c) = encaps(&ek).unwrap();
+    assert_eq!(decaps(&loaded, &c), shared_secret);
+}
+
+#[test]
+fn test_decapsulation_key_eq_detects_last_byte_of_dk_pke() {
+    let (_, dk) = keygen::<MlKem768>().unwrap();
+
+    // dkPKE is not covered by the §7.3 hash check, so a changed byte still loads
+    let mut bytes = dk.as_bytes().to_vec();
+    bytes[384 * MlKem768::K - 1] ^= 1;
+    let modified = DecapsulationKey::<MlKem768>::try_from(&bytes[..]).unwrap();
+    assert_ne!(modified, dk);
+}
+
+#[test]
+fn test_decapsulation_key_try_from_wrong_length() {
+    let (_, dk) = keygen::<MlKem1024>().unwrap();
+
+    assert_eq!(
+        DecapsulationKey::<MlKem1024>::try_from(&dk.as_bytes()[..3167]),
+        Err(Error::InvalidLength { expected: 3168, actual: 3167 })
+    );
+    assert_eq!(
+        DecapsulationKey::<MlKem768>::try_from(&dk.as_bytes()[..]),
+        Err(Error::InvalidLength { expected: 2400, actual: 3168 })
+    );
+}
+
+#[test]
+fn test_decapsulation_key_hash_check() {
+    fn check<P: ParameterSet>() {
+        let (_, dk) = keygen::<P>().unwrap();
+        let bytes = dk.as_bytes().as_ref();
+        let k = P::K;
+
+        // A flipped bit in the embedded ek or in H(ek) is detected
+        for i in [384 * k, 768 * k + 31, 768 * k + 32, 768 * k + 63] {
+            let mut modified = bytes.to_vec();
+            modified[i] ^= 0x01;
+            assert_eq!(DecapsulationKey::<P>::try_from(&modified[..]), Err(Error::InvalidDecapsulationKey));
+        }
+
+        // dkPKE and z are not covered by the hash
+        for i in [0, 384 * k - 1, 768 * k + 64, 768 * k + 95] {
+            let mut modified = bytes.to_vec();
+            modified[i] ^= 0x01;
+            assert!(DecapsulationKey::<P>::try_from(&modified[..]).is_ok());
+        }
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_decapsulation_key_embedded_ek_modulus_check() {
+    fn check<P: ParameterSet>() {
+        let (_, dk) = keygen::<P>().unwrap();
+        let k = P::K;
+
+        // Coefficient 0 of the embedded ek set to 2^12 - 1, with a matching H(ek)
+        let mut modified = dk.as_bytes().as_ref().to_vec();
+        modified[384 * k] = 0xFF;
+        modified[384 * k + 1] |= 0x0F;
+        let h_ek = h(&modified[384 * k..768 * k + 32]);
+        modified[768 * k + 32..768 * k + 64].copy_from_slice(&h_ek);
+
+        assert_eq!(DecapsulationKey::<P>::try_from(&modified[..]), Err(Error::InvalidDecapsulationKey));
+        assert_eq!(EncapsulationKey::<P>::try_from(&modified[384 * k..768 * k + 32]), Err(Error::InvalidEncapsulationKey));
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_decapsulation_key_mixed_up_keys() {
+    let (_, dk1) = keygen::<MlKem768>().unwrap();
+    let (_, dk2) = keygen::<MlKem768>().unwrap();
+
+    // ek and H(ek) taken from two different key files
+    let mut mixed = dk1.as_bytes().to_vec();
+    mixed[1152..2336].copy_from_slice(&dk2.as_bytes()[1152..2336]);
+    assert_eq!(DecapsulationKey::<MlKem768>::try_from(&mixed[..]), Err(Error::InvalidDecapsulationKey));
+}
+
+#[test]
+fn test_ciphertext_try_from() {
+    let (ek, dk) = keygen::<MlKem512>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+
+    let parsed = Ciphertext::<MlKem512>::try_from(&c.as_bytes()[..]).unwrap();
+    assert_eq!(decaps(&dk, &parsed), shared_secret);
+
+    assert_eq!(
+        Ciphertext::<MlKem512>::try_from(&c.as_bytes()[..767]),
+        Err(Error::InvalidLength { expected: 768, actual: 767 })
+    );
+    assert_eq!(
+        Ciphertext::<MlKem1024>::try_from(&c.as_bytes()[..]),
+        Err(Error::InvalidLength { expected: 1568, actual: 768 })
+    );
+}
+
+#[test]
+fn test_keygen_with_pairwise_consistency_test() {
+    fn check<P: ParameterSet>() {
+        let options = KeyGenOptions { pairwise_consistency_test: true };
+        let (ek, dk) = keygen_with_options::<P>(options).unwrap();
+
+        let (shared_secret, c) = encaps(&ek).unwrap();
+        assert_eq!(decaps(&dk, &c), shared_secret);
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_keygen_with_default_options() {
+    assert!(!KeyGenOptions::default().pairwise_consistency_test);
+    assert!(keygen_with_options::<MlKem768>(KeyGenOptions::default()).is_ok());
+}
+
+#[test]
+fn test_pairwise_consistency_test_detects_mismatched_keys() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let (other_ek, _) = keygen::<MlKem768>().unwrap();
+
+    assert_eq!(pairwise_consistency_test(&ek, &dk), Ok(()));
+    assert_eq!(pairwise_consistency_test(&other_ek, &dk), Err(Error::PairwiseConsistencyTestFailed));
+}
+
+#[test]
+fn test_keygen_keeps_seed() {
+    let (ek, dk) = keygen::<MlKem768>().unwrap();
+    let seed = dk.to_seed().expect("Generated keys should know their seed");
+
+    // z is stored at the end of the expanded key
+    assert_eq!(&seed[32..], &dk.as_bytes()[2400 - 32..]);
+
+    let restored = DecapsulationKey::<MlKem768>::from_seed(seed);
+    assert_eq!(restored, dk);
+    assert_eq!(restored.to_seed(), Some(seed));
+    assert_eq!(restored.encapsulation_key(), ek);
+}
+
+#[test]
+fn test_from_seed_deterministic() {
+    fn check<P: ParameterSet>() {
+        let mut seed = [0u8; 64];
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let dk1 = DecapsulationKey::<P>::from_seed(seed);
+        let dk2 = DecapsulationKey::<P>::from_seed(seed);
+        assert_eq!(dk1.as_bytes(), dk2.as_bytes());
+
+        seed[0] ^= 1;
+        assert_ne!(DecapsulationKey::<P>::from_seed(seed).as_bytes(), dk1.as_bytes());
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_from_seed_roundtrip() {
+    fn check<P: ParameterSet>() {
+        let dk = DecapsulationKey::<P>::from_seed([0x42u8; 64]);
+        let ek = dk.encapsulation_key();
+
+        let (shared_secret, c) = encaps(&ek).unwrap();
+        assert_eq!(decaps(&dk, &c), shared_secret);
+    }
+
+    check::<MlKem512>();
+    check::<MlKem768>();
+    check::<MlKem1024>();
+}
+
+#[test]
+fn test_from_seed_only_z_changes() {
+    // d alone determines the K-PKE keys, z only affects implicit rejection
+    let mut seed = [7u8; 64];
+    let dk1 = DecapsulationKey::<MlKem768>::from_seed(seed);
+    seed[63] ^= 1;
+    let dk2 = DecapsulationKey::<MlKem768>::from_seed(seed);
+
+    assert_eq!(dk1.as_bytes()[..2368], dk2.as_bytes()[..2368]);
+    assert_ne!(dk1.as_bytes()[2368..], dk2.as_bytes()[2368..]);
+}
+
+#[test]
+fn test_expanded_key_has_no_seed() {
+    let (_, dk) = keygen::<MlKem768>().unwrap();
+    let loaded = DecapsulationKey::<MlKem768>::try_from(&dk.as_bytes()[..]).unwrap();
+
+    assert_eq!(loaded.to_seed(), None);
+    assert_eq!(loaded, dk);
+}
+
+fn check_len<P: ParameterSet>(ek_len: usize, dk_len: usize, c_len: usize) {
+    assert_eq!(EncapsulationKey::<P>::LEN, ek_len);
+    assert_eq!(DecapsulationKey::<P>::LEN, dk_len);
+    assert_eq!(Ciphertext::<P>::LEN, c_len);
+
+    let (ek, dk) = keygen::<P>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+    assert_eq!(ek.as_bytes().as_ref().len(), EncapsulationKey::<P>::LEN);
+    assert_eq!(dk.as_bytes().as_ref().len(), DecapsulationKey::<P>::LEN);
+    assert_eq!(c.as_bytes().as_ref().len(), Ciphertext::<P>::LEN);
+    assert_eq!(shared_secret.as_bytes().len(), SharedSecret::LEN);
+}
+
+#[test]
+fn test_len_constants() {
+    // FIPS 203 Table 3
+    check_len::<MlKem512>(800, 1632, 768);
+    check_len::<MlKem768>(1184, 2400, 1088);
+    check_len::<MlKem1024>(1568, 3168, 1568);
+}
+
+#[test]
+fn test_typed_values_roundtrip_through_bytes() {
+    let (ek, dk) = keygen::<MlKem1024>().unwrap();
+    let (shared_secret, c) = encaps(&ek).unwrap();
+
+    assert_eq!(EncapsulationKey::<MlKem1024>::try_from(&ek.as_bytes()[..]), Ok(ek));
+    assert_eq!(DecapsulationKey::<MlKem1024>::try_from(&dk.as_bytes()[..]), Ok(dk));
+    assert_eq!(Ciphertext::<MlKem1024>::try_from(&c.as_bytes()[..]), Ok(c));
+    assert_eq!(SharedSecret::try_from(&shared_secret.as_bytes()[..]), Ok(shared_secret));
+}
+
+#[test]
+fn test_shared_secret_from_bytes() {
+    let shared_secret = SharedSecret::from([7u8; 32]);
+    assert_eq!(shared_secret.as_bytes(), &[7u8; 32]);
+    assert_ne!(shared_secret, SharedSecret::from([8u8; 32]));
+
+    assert_eq!(SharedSecret::try_from(&[0u8; 31][..]), Err(Error::InvalidLength { expected: 32, actual: 31 }));
+    assert_eq!(SharedSecret::try_from(&[0u8; 33][..]), Err(Error::InvalidLength { expected: 32, actual: 33 }));
+}
+
+#[test]
+fn test_fallible_apis_return_results() {
+    // With a working OS RNG both calls succeed, failures surface as Error::RngFailure instead of a panic
+    let (ek, dk) = keygen::<MlKem512>().expect("keygen should succeed");
+    let (shared_secret, c) = encaps(&ek).expect("encaps should succeed");
+    assert_eq!(decaps(&dk, &c), shared_secret);
+}
+
+#[test]
+fn test_error_display() {
+    let errors = [
+        Error::InvalidLength { expected: 1184, actual: 1183 },
+        Error::InvalidEncapsulationKey,
+        Error::InvalidDecapsulationKey,
+        Error::PairwiseConsistencyTestFailed,
+        Error::RngFailure,
+        Error::ParameterSetMismatch,
+    ];
+
+    for error in errors {
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert!(!error.to_string().is_empty());
+    }
+    assert_eq!(Error::InvalidLength { expected: 1184, actual: 1183 }.to_string(), "invalid length: expected 1184 bytes, got 1183");
+    assert_eq!(Error::RngFailure.to_string(), "random number generator failed");
+}
+
+#[test]
+fn test_debug_hides_secrets() {
+    let dk = DecapsulationKey::<MlKem768>::from_seed([0x42; 64]);
+    assert_eq!(format!("{dk:?}"), "DecapsulationKey<MlKem768> { .. }");
+    assert_eq!(format!("{:?}", SharedSecret::from([0x42; 32])), "SharedSecret { .. }");
+    assert_eq!(format!("{:?}", Some(SharedSecret::from([0x42; 32]))), "Some(SharedSecret { .. })");
+}
@@ -0,0 +1,152 @@
+// Runs tests/vectors/ml_kem_negative.json through the public KEM API. Every
+// case states whether the input is accepted ("valid"), rejected with an
+// error ("invalid") or decapsulates to J(z || c) ("implicitReject").
+
+use serde_json::Value;
+
+use ml_kem_rs::{cryptographic::j, ml_kem::*, params::ParameterSet, Error, MlKem512, MlKem768, MlKem1024};
+
+fn load() -> Value {
+    let path = format!("{}/tests/vectors/ml_kem_negative.json", env!("CARGO_MANIFEST_DIR"));
+    let data = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {path}: {e}"));
+    serde_json::from_str(&data).unwrap_or_else(|e| panic!("Failed to parse {path}: {e}"))
+}
+
+fn hex(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("Expected a hex string");
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn describe(test: &Value) -> String {
+    format!("tcId {} ({})", test["tcId"], test["comment"].as_str().unwrap_or(""))
+}
+
+// The Error an "invalid" case must be rejected with, from its flags. `key_error` is
+// what a failed key check reports, `expected_len` the length the input type requires.
+fn expected_error(test: &Value, key_error: Option<Error>, expected_len: usize, actual_len: usize) -> Error {
+    let flags: Vec<&str> = test["flags"].as_array().unwrap().iter().map(|f| f.as_str().unwrap()).collect();
+
+    if flags.contains(&"InvalidLength") {
+        Error::InvalidLength { expected: expected_len, actual: actual_len }
+    } else if flags.contains(&"ModulusOverflow") || flags.contains(&"BadHashEk") {
+        key_error.unwrap_or_else(|| panic!("{} has a key check flag but no key", describe(test)))
+    } else {
+        panic!("{} has no flag that explains the rejection", describe(test))
+    }
+}
+
+fn check_encapsulation_key<P: ParameterSet>(test: &Value) {
+    let ek_bytes = hex(&test["ek"]);
+    let result = EncapsulationKey::<P>::try_from(&ek_bytes[..]);
+
+    match test["result"].as_str().unwrap() {
+        "valid" => {
+            let ek = result.unwrap_or_else(|e| panic!("{} rejected: {e}", describe(test)));
+            let _ = encaps(&ek).unwrap();
+        }
+        "invalid" => {
+            let expected = expected_error(test, Some(Error::InvalidEncapsulationKey), P::ENCAPSULATION_KEY_LEN, ek_bytes.len());
+            assert_eq!(result.err(), Some(expected), "{}", describe(test));
+        }
+        other => panic!("Unexpected result {other} for {}", describe(test)),
+    }
+}
+
+fn check_decapsulation_key<P: ParameterSet>(test: &Value) {
+    let dk_bytes = hex(&test["dk"]);
+    let result = DecapsulationKey::<P>::try_from(&dk_bytes[..]);
+
+    match test["result"].as_str().unwrap() {
+        "valid" => assert!(result.is_ok(), "{} rejected: {:?}", describe(test), result.err()),
+        "invalid" => {
+            let expected = expected_error(test, Some(Error::InvalidDecapsulationKey), P::DECAPSULATION_KEY_LEN, dk_bytes.len());
+            assert_eq!(result.err(), Some(expected), "{}", describe(test));
+        }
+        other => panic!("Unexpected result {other} for {}", describe(test)),
+    }
+}
+
+fn check_decapsulation<P: ParameterSet>(group: &Value, test: &Value) {
+    let dk_bytes = hex(&group["dk"]);
+    let dk = DecapsulationKey::<P>::try_from(&dk_bytes[..]).expect("Group dk should be valid");
+    let c_bytes = hex(&test["c"]);
+    let result = Ciphertext::<P>::try_from(&c_bytes[..]);
+
+    if test["result"] == "invalid" {
+        // Ciphertexts have no content check, only the length can be wrong
+        let expected = expected_error(test, None, P::CIPHERTEXT_LEN, c_bytes.len());
+        assert_eq!(result.err(), Some(expected), "{}", describe(test));
+        return;
+    }
+
+    let c = result.unwrap_or_else(|e| panic!("{} rejected: {e}", describe(test)));
+    let k = decaps(&dk, &c);
+    assert_eq!(k.as_bytes().to_vec(), hex(&test["k"]), "K mismatch for {}", describe(test));
+
+    // K̄ = J(z || c) with z the last 32 bytes of dk
+    let mut rejection_input = dk_bytes[dk_bytes.len() - 32..].to_vec();
+    rejection_input.extend_from_slice(&c_bytes);
+    let rejection_secret = j(&rejection_input);
+
+    match test["result"].as_str().unwrap() {
+        "valid" => assert_ne!(k.as_bytes(), &rejection_secret, "{} was implicitly rejected", describe(test)),
+        "implicitReject" => assert_eq!(k.as_bytes(), &rejection_secret, "{} was not implicitly rejected", describe(test)),
+        other => panic!("Unexpected result {other} for {}", describe(test)),
+    }
+}
+
+fn check_group<P: ParameterSet>(group: &Value) -> usize {
+    let tests = group["tests"].as_array().unwrap();
+
+    for test in tests {
+        match group["type"].as_str().unwrap() {
+            "EncapsulationKeyCheck" => check_encapsulation_key::<P>(test),
+            "DecapsulationKeyCheck" => check_decapsulation_key::<P>(test),
+            "Decapsulation" => check_decapsulation::<P>(group, test),
+            other => panic!("Unknown test group type {other}"),
+        }
+    }
+
+    tests.len()
+}
+
+#[test]
+fn test_negative_vectors() {
+    let vectors = load();
+    let mut count = 0;
+
+    for group in vectors["testGroups"].as_array().unwrap() {
+        count += match group["parameterSet"].as_str().unwrap() {
+            "ML-KEM-512" => check_group::<MlKem512>(group),
+            "ML-KEM-768" => check_group::<MlKem768>(group),
+            "ML-KEM-1024" => check_group::<MlKem1024>(group),
+            other => panic!("Unknown parameter set {other}"),
+        };
+    }
+
+    assert_eq!(count as u64, vectors["numberOfTests"].as_u64().unwrap());
+}
+
+#[test]
+fn test_negative_vectors_cover_every_outcome() {
+    let vectors = load();
+    let mut outcomes = std::collections::BTreeSet::new();
+
+    for group in vectors["testGroups"].as_array().unwrap() {
+        for test in group["tests"].as_array().unwrap() {
+            outcomes.insert((group["type"].as_str().unwrap().to_string(), test["result"].as_str().unwrap().to_string()));
+        }
+    }
+
+    for (kind, result) in [
+        ("EncapsulationKeyCheck", "valid"),
+        ("EncapsulationKeyCheck", "invalid"),
+        ("DecapsulationKeyCheck", "valid"),
+        ("DecapsulationKeyCheck", "invalid"),
+        ("Decapsulation", "valid"),
+        ("Decapsulation", "invalid"),
+        ("Decapsulation", "implicitReject"),
+    ] {
+        assert!(outcomes.contains(&(kind.to_string(), result.to_string())), "No {kind} case with result {result}");
+    }
+}
@@ -1,4 +1,4 @@
-use ml_kem_rs::{polynomial::*, SELECTED_PARAMETER_SET};
+use ml_kem_rs::polynomial::*;
 
 #[test]
 fn test_polynomial_creation() {
@@ -13,8 +13,8 @@ fn test_polynomial_creation() {
 #[test]
 fn test_polynomial_indexing() {
     let mut coeffs = [0u16; 256];
-    for i in 0..256 {
-        coeffs[i] = i as u16;
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = i as u16;
     }
     let poly = Poly16::new(&coeffs);
     
@@ -56,10 +56,10 @@ fn test_polynomial_addition_overflow() {
 
 #[test]
 fn test_polynomial_scalar_multiplication() {
-    let coeffs = [10u16; 256];
-    let poly = Poly16::new(&coeffs);
+    let coeffs = [10i16; 256];
+    let poly = PolyI16::new(&coeffs);
     
-    let result = poly * 5u16;
+    let result = (poly * 5).to_canonical();
     
     for i in 0..256 {
         assert_eq!(result[i], 50);
@@ -67,26 +67,44 @@ fn test_polynomial_scalar_multiplication() {
 }
 
 #[test]
+#[allow(clippy::erasing_op)]
 fn test_polynomial_scalar_multiplication_zero() {
-    let coeffs = [42u16; 256];
-    let poly = Poly16::new(&coeffs);
+    let coeffs = [42i16; 256];
+    let poly = PolyI16::new(&coeffs);
     
-    let result = poly * 0u16;
+    let result = (poly * 0).to_canonical();
     
     for i in 0..256 {
         assert_eq!(result[i], 0);
     }
 }
 
+#[test]
+fn test_polynomial_scalar_multiplication_mod_q() {
+    // Products are reduced mod q instead of overflowing
+    let mut coeffs = [0i16; 256];
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = (i as i16 - 128) * 255;
+    }
+    let poly = PolyI16::new(&coeffs);
+
+    let result = poly * 3000;
+
+    for i in 0..256 {
+        assert!(result[i].abs() < 3329);
+        assert_eq!((result[i] as i32).rem_euclid(3329), (coeffs[i] as i32 * 3000).rem_euclid(3329));
+    }
+}
+
 #[test]
 fn test_polynomial_modulo() {
     let mut coeffs = [0u16; 256];
-    for i in 0..256 {
-        coeffs[i] = (i * 100) as u16;
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = (i * 100) as u16;
     }
     let poly = Poly16::new(&coeffs);
     
-    let result = poly % 3329u16;
+    let result = PolyI16::from(poly).to_canonical();
     
     for i in 0..256 {
         assert!(result[i] < 3329, "Coefficient {} should be less than 3329", result[i]);
@@ -104,7 +122,7 @@ fn test_polynomial_modulo_q() {
     coeffs[3] = 100;
     
     let poly = Poly16::new(&coeffs);
-    let result = poly % (SELECTED_PARAMETER_SET.q as u16);
+    let result = PolyI16::from(poly).to_canonical();
     
     assert_eq!(result[0], 0);
     assert_eq!(result[1], 1);
@@ -113,6 +131,7 @@ fn test_polynomial_modulo_q() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn test_polynomial_clone() {
     let coeffs = [42u16; 256];
     let poly1 = Poly16::new(&coeffs);
@@ -186,3 +205,73 @@ fn test_polynomial_zero() {
         assert_eq!(poly[i], 0);
     }
 }
+
+#[test]
+fn test_polynomial_into_iter() {
+    let mut coeffs = [0u16; 256];
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = i as u16;
+    }
+    let poly = Poly16::new(&coeffs);
+
+    // Yields each coefficient once, in order, then stops
+    let collected: Vec<u16> = poly.into_iter().collect();
+    assert_eq!(collected, coeffs.to_vec());
+}
+
+#[test]
+fn test_signed_polynomial_from_unreduced() {
+    // Coefficients at or above 2^15 used to wrap when cast to i16
+    let mut coeffs = [0u16; 256];
+    coeffs[0] = 32767;
+    coeffs[1] = 32768;
+    coeffs[2] = 40000;
+    coeffs[3] = 65535;
+    let signed: PolyI16 = Poly16::new(&coeffs).into();
+
+    assert_eq!(signed[0], -523);
+    assert_eq!(signed[1], -522);
+    assert_eq!(signed[2], 52);
+    assert_eq!(signed[3], -1045);
+}
+
+#[test]
+fn test_signed_polynomial_canonical_roundtrip() {
+    for start in (0..3329u16).step_by(256) {
+        let mut coeffs = [0u16; 256];
+        for (i, coeff) in coeffs.iter_mut().enumerate() {
+            *coeff = (start + i as u16).min(3328);
+        }
+        let poly = Poly16::new(&coeffs);
+
+        let signed: PolyI16 = poly.into();
+        let back: Poly16 = signed.into();
+        for i in 0..256 {
+            // Centered representative of the same class
+            assert!(signed[i].abs() <= 1664);
+            assert_eq!((signed[i] as i32).rem_euclid(3329), coeffs[i] as i32);
+            assert_eq!(back[i], coeffs[i]);
+        }
+    }
+}
+
+#[test]
+fn test_signed_polynomial_lazy_reduction() {
+    let mut coeffs = [0i16; 256];
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = (i as i16 - 128) * 127;
+    }
+    let poly = PolyI16::new(&coeffs);
+
+    // Coefficients leave [0, q) freely, reduction is deferred until asked for
+    let sum = poly + poly;
+    let difference = poly - poly - poly;
+    let reduced = sum.reduce();
+    let canonical = difference.to_canonical();
+    for i in 0..256 {
+        assert_eq!(sum[i], 2 * coeffs[i]);
+        assert!(reduced[i].abs() <= 1664);
+        assert_eq!((reduced[i] as i32 - 2 * coeffs[i] as i32).rem_euclid(3329), 0);
+        assert_eq!(canonical[i] as i32, (-(coeffs[i] as i32)).rem_euclid(3329));
+    }
+}
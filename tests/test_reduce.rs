@@ -0,0 +1,82 @@
+use ml_kem_rs::{auxiliary::*, reduce::*, Q};
+
+const QI: i32 = Q as i32;
+
+#[test]
+fn test_barrett_reduce_all_inputs() {
+    for a in i16::MIN..=i16::MAX {
+        let r = barrett_reduce(a);
+        assert!((-(QI - 1) / 2..=(QI - 1) / 2).contains(&(r as i32)), "barrett_reduce({a}) = {r} out of range");
+        assert_eq!((a as i32 - r as i32).rem_euclid(QI), 0, "barrett_reduce({a}) = {r} not congruent");
+    }
+}
+
+#[test]
+fn test_barrett_reduce_u16_all_inputs() {
+    for a in u16::MIN..=u16::MAX {
+        let r = barrett_reduce_u16(a);
+        assert!((-(QI - 1) / 2..=(QI - 1) / 2).contains(&(r as i32)), "barrett_reduce_u16({a}) = {r} out of range");
+        assert_eq!((a as i32 - r as i32).rem_euclid(QI), 0, "barrett_reduce_u16({a}) = {r} not congruent");
+    }
+}
+
+#[test]
+fn test_canonical_all_inputs() {
+    for a in i16::MIN..=i16::MAX {
+        assert_eq!(canonical(a) as i32, (a as i32).rem_euclid(QI), "canonical({a})");
+    }
+}
+
+#[test]
+fn test_montgomery_reduce() {
+    // montgomery_reduce(a)·2^16 ≡ a (mod q) for |a| < q·2^15
+    let bound = QI << 15;
+    let mut a = -bound + 1;
+    while a < bound {
+        let r = montgomery_reduce(a) as i32;
+        assert!(r > -QI && r < QI, "montgomery_reduce({a}) = {r} out of range");
+        assert_eq!(((r << 16) - a).rem_euclid(QI), 0, "montgomery_reduce({a}) = {r} not congruent");
+        a += 9973;
+    }
+}
+
+#[test]
+fn test_montgomery_constants() {
+    assert_eq!(R2 as i32, (1i64 << 32).rem_euclid(QI as i64) as i32);
+
+    for a in 0..QI as i16 {
+        // to_montgomery then montgomery_reduce is the identity
+        assert_eq!(canonical(montgomery_reduce(to_montgomery(a) as i32)), a as u16);
+        assert_eq!(canonical(to_montgomery(a)) as i32, ((a as i32) << 16).rem_euclid(QI));
+    }
+}
+
+#[test]
+fn test_fqmul() {
+    for a in (0..QI as i16).step_by(7) {
+        for b in (0..QI as i16).step_by(11) {
+            assert_eq!(canonical(fqmul(a, to_montgomery(b))) as i32, (a as i32 * b as i32) % QI);
+        }
+    }
+}
+
+#[test]
+fn test_conditional_add_and_subtract() {
+    for a in -QI..QI {
+        assert_eq!(caddq(a as i16) as i32, a.rem_euclid(QI));
+    }
+    for a in 0..2 * QI {
+        assert_eq!(csubq(a as i16) as i32, a % QI);
+    }
+}
+
+#[test]
+fn test_zeta_tables_in_montgomery_form() {
+    for i in 0..128 {
+        assert_eq!(canonical(get_zeta_montgomery(i)) as i32, ((get_zeta_from_index(i) as i32) << 16).rem_euclid(QI));
+        assert_eq!(canonical(get_gamma_montgomery(i)) as i32, ((get_gamma_from_index(i) as i32) << 16).rem_euclid(QI));
+    }
+
+    // FIPS 203 Appendix A: ζ^BitRev7(1) = 1729
+    assert_eq!(get_zeta_from_index(1), 1729);
+}
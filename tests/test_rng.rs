@@ -0,0 +1,142 @@
+use rand_core::{CryptoRng, CryptoRngCore, RngCore};
+
+use ml_kem_rs::{hazmat::{encaps_internal, keygen_internal}, ml_kem::*, AnyMlKem, Error, MlKem512, MlKem768, MlKem1024};
+#[cfg(feature = "getrandom")]
+use ml_kem_rs::rng::OsRng;
+
+// Emits 0, 1, 2, ... so the seeds drawn by keygen and encaps are known
+struct CountingRng(u8);
+
+impl RngCore for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CountingRng {}
+
+// Serves `remaining` bytes, then fails every request
+struct FailingRng {
+    remaining: usize,
+}
+
+impl RngCore for FailingRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap()
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        if dest.len() > self.remaining {
+            return Err(rand_core::Error::from(core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap()));
+        }
+        self.remaining -= dest.len();
+        dest.fill(0x42);
+        Ok(())
+    }
+}
+
+impl CryptoRng for FailingRng {}
+
+fn counting_seed(start: u8) -> [u8; 32] {
+    core::array::from_fn(|i| start + i as u8)
+}
+
+#[test]
+fn test_keygen_with_rng_draws_d_then_z() {
+    let (ek, dk) = keygen_with_rng::<MlKem768>(&mut CountingRng(0)).unwrap();
+    let (expected_ek, expected_dk) = keygen_internal::<MlKem768>(counting_seed(0), counting_seed(32));
+
+    assert_eq!(ek, expected_ek);
+    assert_eq!(dk, expected_dk);
+}
+
+#[test]
+fn test_encaps_with_rng_draws_m() {
+    let (ek, dk) = keygen_with_rng::<MlKem1024>(&mut CountingRng(0)).unwrap();
+
+    let (shared_secret, c) = encaps_with_rng(&ek, &mut CountingRng(100)).unwrap();
+    assert_eq!((shared_secret, c), encaps_internal(&ek, counting_seed(100)));
+    assert_eq!(decaps(&dk, &c), shared_secret);
+}
+
+#[test]
+fn test_rng_failures_are_errors() {
+    assert_eq!(keygen_with_rng::<MlKem512>(&mut FailingRng { remaining: 0 }).err(), Some(Error::RngFailure));
+    // d is drawn, z is not
+    assert_eq!(keygen_with_rng::<MlKem512>(&mut FailingRng { remaining: 32 }).err(), Some(Error::RngFailure));
+
+    let (ek, _) = keygen_with_rng::<MlKem512>(&mut FailingRng { remaining: 64 }).unwrap();
+    assert_eq!(encaps_with_rng(&ek, &mut FailingRng { remaining: 0 }).err(), Some(Error::RngFailure));
+
+    // The pairwise consistency test needs 32 more bytes after keygen
+    let options = KeyGenOptions { pairwise_consistency_test: true };
+    assert_eq!(keygen_with_options_and_rng::<MlKem768>(options, &mut FailingRng { remaining: 64 }).err(), Some(Error::RngFailure));
+    assert!(keygen_with_options_and_rng::<MlKem768>(options, &mut FailingRng { remaining: 96 }).is_ok());
+
+    assert_eq!(AnyMlKem::MlKem1024.keygen_with_rng(&mut FailingRng { remaining: 0 }).err(), Some(Error::RngFailure));
+    assert_eq!(AnyMlKem::MlKem512.keygen_with_options_and_rng(options, &mut FailingRng { remaining: 64 }).err(), Some(Error::RngFailure));
+    assert!(AnyMlKem::MlKem512.keygen_with_options_and_rng(options, &mut FailingRng { remaining: 96 }).is_ok());
+}
+
+#[test]
+fn test_any_with_rng_matches_generic() {
+    let (ek, dk) = AnyMlKem::MlKem768.keygen_with_rng(&mut CountingRng(0)).unwrap();
+    let (expected_ek, _) = keygen_with_rng::<MlKem768>(&mut CountingRng(0)).unwrap();
+    assert_eq!(ek.as_bytes(), &expected_ek.as_bytes()[..]);
+
+    let (shared_secret, c) = ek.encaps_with_rng(&mut CountingRng(7)).unwrap();
+    assert_eq!(dk.decaps(&c), Ok(shared_secret));
+
+    let options = KeyGenOptions { pairwise_consistency_test: true };
+    let (ek, _) = AnyMlKem::MlKem1024.keygen_with_options_and_rng(options, &mut CountingRng(0)).unwrap();
+    let (expected_ek, _) = keygen_with_rng::<MlKem1024>(&mut CountingRng(0)).unwrap();
+    assert_eq!(ek.as_bytes(), &expected_ek.as_bytes()[..]);
+}
+
+#[test]
+fn test_rng_trait_object() {
+    let mut rng = CountingRng(0);
+    let rng: &mut dyn CryptoRngCore = &mut rng;
+
+    let (ek, dk) = keygen_with_rng::<MlKem768>(rng).unwrap();
+    let (shared_secret, c) = encaps_with_rng(&ek, rng).unwrap();
+    assert_eq!(decaps(&dk, &c), shared_secret);
+}
+
+#[test]
+#[cfg(feature = "getrandom")]
+fn test_os_rng() {
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    OsRng.try_fill_bytes(&mut a).unwrap();
+    OsRng.try_fill_bytes(&mut b).unwrap();
+    assert_ne!(a, b);
+
+    let (ek, dk) = keygen_with_rng::<MlKem512>(&mut OsRng).unwrap();
+    let (shared_secret, c) = encaps_with_rng(&ek, &mut OsRng).unwrap();
+    assert_eq!(decaps(&dk, &c), shared_secret);
+}